@@ -0,0 +1,223 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use crate::entities::*;
+use crate::game::{Game, GameMode};
+
+/// How well the computer plays, from flawless to human-like
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum AiDifficulty {
+    Perfect,
+    Hard,
+    Normal,
+    Human,
+}
+
+impl AiDifficulty {
+    /// Settings menu order
+    pub const ALL: [AiDifficulty; 4] = [
+        AiDifficulty::Perfect,
+        AiDifficulty::Hard,
+        AiDifficulty::Normal,
+        AiDifficulty::Human,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AiDifficulty::Perfect => "Perfect",
+            AiDifficulty::Hard => "Hard",
+            AiDifficulty::Normal => "Normal",
+            AiDifficulty::Human => "Human",
+        }
+    }
+
+    /// Frames between seeing the ball and reacting to it
+    fn reaction_frames(self) -> usize {
+        match self {
+            AiDifficulty::Perfect => 0,
+            AiDifficulty::Hard => 4,
+            AiDifficulty::Normal => 10,
+            AiDifficulty::Human => 18,
+        }
+    }
+
+    /// Largest aiming error in pixels, rolled once per approaching ball
+    fn error_px(self) -> i32 {
+        match self {
+            AiDifficulty::Perfect => 0,
+            AiDifficulty::Hard => 12,
+            AiDifficulty::Normal => 35,
+            AiDifficulty::Human => 70,
+        }
+    }
+
+    /// Whether the prediction accounts for spin curving the ball
+    fn reads_spin(self) -> bool {
+        matches!(self, AiDifficulty::Perfect | AiDifficulty::Hard)
+    }
+
+    /// Whether falling capsules are worth chasing
+    fn chases_bonuses(self) -> bool {
+        self != AiDifficulty::Human
+    }
+
+    /// Frames to wait before serving a resting ball
+    fn serve_delay(self) -> u32 {
+        match self {
+            AiDifficulty::Perfect => 0,
+            AiDifficulty::Hard => 10,
+            AiDifficulty::Normal => 20,
+            AiDifficulty::Human => 25,
+        }
+    }
+}
+
+/// What the controller wants to do this tick
+pub struct AiInput {
+    pub target_x: i32, // Desired paddle center
+    pub action: bool,  // Press launch / fire (space)
+}
+
+/// Computer-controlled paddle. Reads the game each tick and produces a paddle target.
+pub struct AiController {
+    pub difficulty: AiDifficulty,
+    pub player: usize,
+    planned: VecDeque<i32>, // Targets waiting out the reaction delay
+    error: i32,             // Aiming error for the current approach
+    approaching: bool,      // A ball was heading for the paddle last tick
+    serve_timer: u32,
+    // Own generator: drawing from the gameplay one would shift every capsule
+    // and boss roll, and a replay (which has no autopilot) would go out of sync.
+    // Seeded so the same run seed and settings play the same autopilot run.
+    rng: StdRng,
+}
+
+/// Frames to look ahead when predicting where a ball lands
+const PREDICTION_FRAMES: u32 = 600;
+
+impl AiController {
+    pub fn new(difficulty: AiDifficulty, player: usize, seed: u64) -> Self {
+        AiController {
+            difficulty,
+            player,
+            planned: VecDeque::new(),
+            error: 0,
+            approaching: false,
+            serve_timer: 0,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn tick(&mut self, game: &Game) -> AiInput {
+        let paddle = game.paddle_of(self.player);
+        let paddle_center = paddle.x + paddle.width / 2;
+
+        let target = match self.intercept(game, paddle) {
+            Some(x) => {
+                // New approach - roll a fresh aiming error
+                if !self.approaching {
+                    let max_error = self.difficulty.error_px();
+                    self.error = if max_error > 0 { self.rng.gen_range(-max_error..=max_error) } else { 0 };
+                    self.approaching = true;
+                }
+                x + self.error
+            }
+            None => {
+                self.approaching = false;
+                self.idle_target(game, paddle).unwrap_or(paddle_center)
+            }
+        };
+
+        // React to what was seen `reaction_frames` ago
+        self.planned.push_back(target);
+        let target_x = if self.planned.len() > self.difficulty.reaction_frames() {
+            self.planned.pop_front().unwrap_or(paddle_center)
+        } else {
+            paddle_center
+        };
+
+        AiInput {
+            target_x,
+            action: self.wants_action(game, paddle),
+        }
+    }
+
+    /// Paddle center needed to meet the ball that reaches this paddle first
+    fn intercept(&self, game: &Game, paddle: &Paddle) -> Option<i32> {
+        let top = paddle.is_top();
+        let line_y = if top {
+            (paddle.y + PADDLE_HEIGHT) as f32
+        } else {
+            (paddle.y - BALL_SIZE) as f32
+        };
+        let top_goal = game.mode == GameMode::Versus;
+
+        let mut best: Option<(u32, f32)> = None;
+        for ball in game.balls.iter().filter(|b| b.active && !b.attached_to_paddle) {
+            let heading_here = if top { ball.vel_y < 0.0 } else { ball.vel_y > 0.0 };
+            if !heading_here {
+                continue;
+            }
+
+            // Run the ball's own physics forward, ignoring blocks
+            let mut ghost = ball.clone();
+            ghost.trail_positions.clear();
+            if !self.difficulty.reads_spin() {
+                ghost.spin = 0.0;
+            }
+            for frame in 0..PREDICTION_FRAMES {
+                ghost.update(&game.modifiers, top_goal);
+                let arrived = if top { ghost.y <= line_y } else { ghost.y >= line_y };
+                if arrived || !ghost.active {
+                    if best.is_none_or(|(frames, _)| frame < frames) {
+                        best = Some((frame, ghost.x + BALL_SIZE as f32 / 2.0));
+                    }
+                    break;
+                }
+            }
+        }
+
+        best.map(|(_, x)| x as i32)
+    }
+
+    /// Where to wait when no ball is coming: under a capsule, or under the nearest ball
+    fn idle_target(&self, game: &Game, paddle: &Paddle) -> Option<i32> {
+        let top = paddle.is_top();
+
+        if self.difficulty.chases_bonuses() {
+            let capsule = game.bonuses.iter()
+                .filter(|b| b.active && (b.vel_y < 0.0) == top)
+                .filter(|b| !matches!(b.bonus_type, BonusType::Shrink | BonusType::SpeedUp))
+                .min_by_key(|b| (b.y as i32 - paddle.y).abs());
+            if let Some(bonus) = capsule {
+                return Some(bonus.rect().center().x());
+            }
+        }
+
+        game.balls.iter()
+            .filter(|b| b.active)
+            .min_by_key(|b| (b.y as i32 - paddle.y).abs())
+            .map(|b| b.x as i32 + BALL_SIZE / 2)
+    }
+
+    /// Serve resting balls after a short delay; fire rockets at blocks straight ahead
+    fn wants_action(&mut self, game: &Game, paddle: &Paddle) -> bool {
+        let resting = game.balls.iter().any(|b| b.attached_to_paddle && b.owner == self.player);
+        if resting {
+            self.serve_timer += 1;
+            if self.serve_timer >= self.difficulty.serve_delay() {
+                self.serve_timer = 0;
+                return true;
+            }
+            return false;
+        }
+        self.serve_timer = 0;
+
+        let center = paddle.x + paddle.width / 2;
+        paddle.rocket_ammo() > 0
+            && game.blocks.iter().any(|b| {
+                b.active && b.block_type != BlockType::Undestroyable && (b.x..b.x + b.width).contains(&center)
+            })
+    }
+}
@@ -0,0 +1,114 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::ai::{AiController, AiDifficulty};
+use crate::game::{Game, GameState};
+use crate::rng;
+
+/// No input on the main menu for 20 seconds starts the demo
+const IDLE_FRAMES: u32 = 20 * 60;
+
+/// Each demo level plays for at most 30 seconds
+const DEMO_LEVEL_FRAMES: u32 = 30 * 60;
+
+/// Demo levels are picked from the campaign plus the first infinite levels
+const DEMO_LEVELS: usize = 12;
+
+/// A self-running game played by the autopilot
+pub struct Demo {
+    pub game: Game,
+    ai: AiController,
+    frames: u32,
+}
+
+impl Demo {
+    /// Random level, never the same one twice in a row
+    fn new(previous_level: Option<usize>) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut level = rng.gen_range(1..=DEMO_LEVELS);
+        while Some(level) == previous_level {
+            level = rng.gen_range(1..=DEMO_LEVELS);
+        }
+
+        let mut game = Game::new_level(level);
+        game.state = GameState::Playing;
+
+        Demo {
+            game,
+            ai: AiController::new(AiDifficulty::Hard, 0, rng::game_rng().gen()),
+            frames: 0,
+        }
+    }
+
+    fn update(&mut self) {
+        self.frames += 1;
+
+        // The demo plays silently
+        let input = self.ai.tick(&self.game);
+        self.game.steer_paddle(self.ai.player, input.target_x);
+        if input.action {
+            self.game.press_action(self.ai.player, &mut |_| {});
+        }
+        self.game.update(&mut |_| {});
+    }
+
+    /// Level cleared, lost, or shown long enough
+    fn is_finished(&self) -> bool {
+        self.game.state != GameState::Playing || self.frames >= DEMO_LEVEL_FRAMES
+    }
+}
+
+/// Title screen attract mode: an AI demo after the main menu sits idle
+pub struct AttractMode {
+    idle_frames: u32,
+    demo: Option<Demo>,
+    rng: StdRng, // The demo's own gameplay generator, so the paused run's stream is not advanced
+}
+
+impl AttractMode {
+    pub fn new() -> Self {
+        AttractMode {
+            idle_frames: 0,
+            demo: None,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Any player input. Stops a running demo and returns true, so the input can be swallowed.
+    pub fn on_input(&mut self) -> bool {
+        self.idle_frames = 0;
+        self.demo.take().is_some()
+    }
+
+    /// Count idle time while the main menu is showing, or advance the demo
+    pub fn update(&mut self, on_main_menu: bool) {
+        if let Some(ref mut demo) = self.demo {
+            rng::with_rng(&mut self.rng, || {
+                demo.update();
+                if demo.is_finished() {
+                    // Cycle to another level (and background)
+                    *demo = Demo::new(Some(demo.game.current_level));
+                }
+            });
+            return;
+        }
+
+        if !on_main_menu {
+            self.idle_frames = 0;
+            return;
+        }
+
+        self.idle_frames += 1;
+        if self.idle_frames >= IDLE_FRAMES {
+            self.demo = Some(rng::with_rng(&mut self.rng, || Demo::new(None)));
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.demo.is_some()
+    }
+
+    /// The demo game, if one is playing
+    pub fn game(&self) -> Option<&Game> {
+        self.demo.as_ref().map(|demo| &demo.game)
+    }
+}
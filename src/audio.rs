@@ -1,6 +1,7 @@
 use sdl2::mixer::{Channel, Chunk, Music};
 use std::path::Path;
 use rand::Rng;
+use crate::packs::resolve;
 
     pub struct AudioManager {
     bounce_sound: Option<Chunk>,
@@ -10,6 +11,7 @@ use rand::Rng;
     explosion_sound: Option<Chunk>,
     songs: Vec<String>,
     current_song_index: usize,
+    track: Option<String>, // Level music, loops instead of the playlist
     music_volume: i32,
     sfx_volume: i32,
     music_muted: bool,
@@ -23,10 +25,31 @@ impl AudioManager {
         sdl2::mixer::open_audio(44100, sdl2::mixer::AUDIO_S16LSB, 2, 1024)?;
         sdl2::mixer::allocate_channels(4);
 
+        let mut manager = AudioManager {
+            bounce_sound: None,
+            oh_sound: None,
+            load_sound: None,
+            breaking_glass_sound: None,
+            explosion_sound: None,
+            songs: Vec::new(),
+            current_song_index: 0,
+            track: None,
+            music_volume: 64, // Default to 50% volume (max is 128)
+            sfx_volume: 64,   // Default to 50% volume (max is 128)
+            music_muted: false,
+            sfx_muted: false,
+            music_should_play: false,
+        };
+        manager.load_assets();
+        Ok(manager)
+    }
+
+    /// Load sound effects and the music playlist, from the selected pack where it has them
+    pub fn load_assets(&mut self) {
         // Try to load MP3 bounce sound (fallback to WAV if MP3 not found)
-        let bounce_sound = Chunk::from_file(Path::new("assets/ball.mp3"))
-            .or_else(|_| Chunk::from_file(Path::new("assets/ball_bounce.mp3")))
-            .or_else(|_| Chunk::from_file(Path::new("assets/ball_bounce.wav")))
+        let bounce_sound = Chunk::from_file(Path::new(&resolve("assets/ball.mp3")))
+            .or_else(|_| Chunk::from_file(Path::new(&resolve("assets/ball_bounce.mp3"))))
+            .or_else(|_| Chunk::from_file(Path::new(&resolve("assets/ball_bounce.wav"))))
             .ok();
 
         if bounce_sound.is_none() {
@@ -36,25 +59,25 @@ impl AudioManager {
         // Note: audio files must be 44.1kHz
 
         // Load drop-sound-effect-240899.mp3
-        let oh_sound = Chunk::from_file(Path::new("assets/drop-sound-effect-240899.mp3")).ok();
+        let oh_sound = Chunk::from_file(Path::new(&resolve("assets/drop-sound-effect-240899.mp3"))).ok();
         if oh_sound.is_none() {
             eprintln!("Warning: Could not load assets/drop-sound-effect-240899.mp3");
         }
 
         // Load load.mp3
-        let load_sound = Chunk::from_file(Path::new("assets/load.mp3")).ok();
+        let load_sound = Chunk::from_file(Path::new(&resolve("assets/load.mp3"))).ok();
         if load_sound.is_none() {
             eprintln!("Warning: Could not load assets/load.mp3");
         }
 
         // Load breaking-glass.mp3
-        let breaking_glass_sound = Chunk::from_file(Path::new("assets/breaking-glass.mp3")).ok();
+        let breaking_glass_sound = Chunk::from_file(Path::new(&resolve("assets/breaking-glass.mp3"))).ok();
         if breaking_glass_sound.is_none() {
             eprintln!("Warning: Could not load assets/breaking-glass.mp3");
         }
 
         // Load swish-swoosh-woosh-sfx-27-357164.mp3
-        let explosion_sound = match Chunk::from_file(Path::new("assets/swish-swoosh-woosh-sfx-27-357164.mp3")) {
+        let explosion_sound = match Chunk::from_file(Path::new(&resolve("assets/swish-swoosh-woosh-sfx-27-357164.mp3"))) {
             Ok(sound) => Some(sound),
             Err(e) => {
                 eprintln!("Warning: Could not load assets/swish-swoosh-woosh-sfx-27-357164.mp3: {}", e);
@@ -65,7 +88,7 @@ impl AudioManager {
         // Setup song playlist - dynamically load all .mp3 files from assets directory
         let mut songs = Vec::new();
         
-        if let Ok(entries) = std::fs::read_dir("assets/music") {
+        if let Ok(entries) = std::fs::read_dir(resolve("assets/music")) {
             for entry in entries.flatten() {
                 if let Ok(path) = entry.path().canonicalize() {
                     if let Some(ext) = path.extension() {
@@ -99,21 +122,13 @@ impl AudioManager {
             0
         };
 
-
-        Ok(AudioManager {
-            bounce_sound,
-            oh_sound,
-            load_sound,
-            breaking_glass_sound,
-            explosion_sound,
-            songs,
-            current_song_index,
-            music_volume: 64, // Default to 50% volume (max is 128)
-            sfx_volume: 64,   // Default to 50% volume (max is 128)
-            music_muted: false,
-            sfx_muted: false,
-            music_should_play: false,
-        })
+        self.bounce_sound = bounce_sound;
+        self.oh_sound = oh_sound;
+        self.load_sound = load_sound;
+        self.breaking_glass_sound = breaking_glass_sound;
+        self.explosion_sound = explosion_sound;
+        self.songs = songs;
+        self.current_song_index = current_song_index;
     }
 
     pub fn play_bounce(&self) {
@@ -157,15 +172,19 @@ impl AudioManager {
     }
 
     pub fn update(&mut self) {
-        if !self.music_muted && self.music_should_play && !self.songs.is_empty() {
+        if !self.music_muted && self.music_should_play && (self.track.is_some() || !self.songs.is_empty()) {
             // Auto-advance to next random song when current finishes
             if !Music::is_playing() {
-                // Pick a random song
-                let mut rng = rand::thread_rng();
-                self.current_song_index = rng.gen_range(0..self.songs.len());
-                
-                let song_path = &self.songs[self.current_song_index];
-                if let Ok(music) = Music::from_file(song_path) {
+                let song_path = if let Some(ref track) = self.track {
+                    track.clone()
+                } else {
+                    // Pick a random song
+                    let mut rng = rand::thread_rng();
+                    self.current_song_index = rng.gen_range(0..self.songs.len());
+                    self.songs[self.current_song_index].clone()
+                };
+
+                if let Ok(music) = Music::from_file(&song_path) {
                     Music::set_volume(self.music_volume);
                     // Play ONCE (1), not loop (-1)
                     // This allows is_playing() to return false when done
@@ -175,20 +194,22 @@ impl AudioManager {
                     std::mem::forget(music);
                 } else {
                     eprintln!("Warning: Could not load {}", song_path);
+                    // Back to the playlist rather than retrying every frame
+                    self.track = None;
                 }
             }
         }
     }
 
     pub fn play_music(&mut self) {
-        if self.songs.is_empty() || self.music_muted {
+        if (self.track.is_none() && self.songs.is_empty()) || self.music_muted {
             return;
         }
 
         self.music_should_play = true;
         
-        // Start playing the current song
-        let song_path = &self.songs[self.current_song_index];
+        // Start playing the level track or the current song
+        let song_path = self.track.as_ref().unwrap_or_else(|| &self.songs[self.current_song_index]);
         if let Ok(music) = Music::from_file(song_path) {
             Music::set_volume(self.music_volume);
             // Play ONCE (1), not loop (-1)
@@ -201,7 +222,20 @@ impl AudioManager {
         }
     }
 
-    
+    /// Switch to a level's own track, None goes back to the playlist
+    pub fn set_track(&mut self, track: Option<&str>) {
+        let track = track.map(resolve);
+        if track == self.track {
+            return;
+        }
+        self.track = track;
+
+        // update() starts the new music once this one has stopped
+        if self.music_should_play && !self.music_muted {
+            Music::halt();
+        }
+    }
+
     pub fn stop_music(&mut self) {
         Music::halt();
         self.music_should_play = false;
@@ -0,0 +1,103 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::campaign::{BonusStageDef, Campaign};
+use crate::entities::*;
+use crate::rng;
+
+/// Portal tuning, as stored in settings.json
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct PortalConfig {
+    pub threshold: f32,      // Ball speed that opens the portal (px/s)
+    pub points: u32,         // Awarded when the portal opens
+    pub bonus_stage: bool,   // Warp to a bonus stage before the next level
+    pub stage_seconds: u32,  // Length of a bonus stage (unless the stage sets its own)
+    pub stage: Option<String>, // Always warp to this bonus stage (by name); None takes turns
+    pub block_points: u32,   // Per block destroyed in a bonus stage
+    pub capsule_points: u32, // Per capsule caught in a bonus stage
+}
+
+impl Default for PortalConfig {
+    fn default() -> Self {
+        PortalConfig {
+            threshold: 3600.0,
+            points: 5000,
+            bonus_stage: false,
+            stage_seconds: 20,
+            stage: None,
+            block_points: 100,
+            capsule_points: 250,
+        }
+    }
+}
+
+/// Capsule rain drops one capsule this often
+const CAPSULE_INTERVAL_FRAMES: u32 = 20;
+
+/// Running bonus stage. Lost balls cost nothing here; the stage ends on time
+/// (or when the block field is cleared).
+pub struct BonusStage {
+    pub def: BonusStageDef,
+    pub frames_left: u32,
+    spawn_timer: u32,
+}
+
+impl BonusStage {
+    pub fn new(def: BonusStageDef, portal: &PortalConfig) -> Self {
+        let seconds = def.seconds.unwrap_or(portal.stage_seconds);
+        BonusStage {
+            def,
+            frames_left: seconds * 60,
+            spawn_timer: 0,
+        }
+    }
+
+    /// Stage to warp to: the one the portal setting names, otherwise the
+    /// campaign's stages take turns by level number
+    pub fn choose(campaign: &Campaign, portal: &PortalConfig, level: usize) -> Option<BonusStageDef> {
+        if let Some(ref name) = portal.stage {
+            match campaign.bonus_stage(name) {
+                Some(stage) => return Some(stage.clone()),
+                None => eprintln!("No bonus stage named {}, taking turns instead", name),
+            }
+        }
+        if campaign.bonus_stages.is_empty() {
+            return None;
+        }
+        Some(campaign.bonus_stages[level % campaign.bonus_stages.len()].clone())
+    }
+
+    /// Stage layout, loaded like any level's
+    pub fn create_blocks(&self) -> Vec<Block> {
+        self.def.level.blocks.create()
+    }
+
+    /// Count down. Returns a capsule to drop, if one is due.
+    pub fn update(&mut self) -> Option<Bonus> {
+        self.frames_left = self.frames_left.saturating_sub(1);
+
+        if !self.def.capsule_rain {
+            return None;
+        }
+        self.spawn_timer += 1;
+        if self.spawn_timer < CAPSULE_INTERVAL_FRAMES {
+            return None;
+        }
+        self.spawn_timer = 0;
+
+        let mut rng = rng::game_rng();
+        let x = rng.gen_range(40.0..(WINDOW_WIDTH - 80) as f32);
+        let bonus_type = self.def.level.bonus_table.unwrap_or_default().roll(&mut rng);
+        let mut bonus = Bonus::new(x, -40.0, bonus_type);
+        bonus.vel_y = 3.0;
+        Some(bonus)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.frames_left == 0
+    }
+
+    pub fn seconds_left(&self) -> u32 {
+        self.frames_left.div_ceil(60)
+    }
+}
@@ -0,0 +1,249 @@
+use sdl2::rect::Rect;
+use rand::Rng;
+use crate::entities::*;
+use crate::campaign::{campaign, LevelBlocks};
+use crate::rng;
+
+pub const BOSS_WIDTH: i32 = 360;
+pub const BOSS_HEIGHT: i32 = 110;
+pub const BOSS_Y: i32 = 110;
+pub const PROJECTILE_SIZE: i32 = 14;
+
+/// Boss encounters: wherever campaign.json puts them, then every 5th infinite level
+pub fn is_boss_level(level: usize) -> bool {
+    match campaign().level(level) {
+        Some(def) => def.blocks == LevelBlocks::Boss,
+        None => level.is_multiple_of(5),
+    }
+}
+
+/// Boss phases, driven by remaining HP
+#[derive(Clone, Copy, PartialEq)]
+pub enum BossPhase {
+    One,   // Single aimed shots
+    Two,   // Three-way spread
+    Three, // Rapid aimed fire + downward ring bursts
+}
+
+/// Something the game loop has to react to (sounds, particles, score)
+#[derive(Clone, Copy, PartialEq)]
+pub enum BossEvent {
+    None,
+    Damaged,
+    PhaseChanged(BossPhase),
+    Defeated,
+}
+
+pub struct BossProjectile {
+    pub x: f32,
+    pub y: f32,
+    pub vel_x: f32,
+    pub vel_y: f32,
+    pub active: bool,
+}
+
+impl BossProjectile {
+    pub fn new(x: f32, y: f32, vel_x: f32, vel_y: f32) -> Self {
+        BossProjectile {
+            x,
+            y,
+            vel_x,
+            vel_y,
+            active: true,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.y > WINDOW_HEIGHT as f32
+            || self.x < -(PROJECTILE_SIZE as f32)
+            || self.x > WINDOW_WIDTH as f32
+        {
+            self.active = false;
+        }
+    }
+
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x as i32, self.y as i32, PROJECTILE_SIZE as u32, PROJECTILE_SIZE as u32)
+    }
+}
+
+/// Giant entity occupying the block area
+pub struct Boss {
+    pub x: f32,
+    pub y: f32,
+    pub hp: u32,
+    pub max_hp: u32,
+    pub phase: BossPhase,
+    pub flash_timer: u32,        // Frames of white hit-flash remaining
+    pub invulnerable_timer: u32, // Grace period after a phase change
+    pub attack_timer: u32,       // Frames until next attack
+    pub burst_timer: u32,        // Frames until next ring burst (phase 3)
+    pub death_timer: u32,        // Frames since defeat (death animation)
+    pub frame_count: u64,
+}
+
+impl Boss {
+    pub fn new(level: usize) -> Self {
+        // Later bosses soak up more hits
+        let max_hp = 30 + (level.saturating_sub(campaign().last_level()) as u32 / 5) * 10;
+
+        Boss {
+            x: (WINDOW_WIDTH as i32 - BOSS_WIDTH) as f32 / 2.0,
+            y: BOSS_Y as f32,
+            hp: max_hp,
+            max_hp,
+            phase: BossPhase::One,
+            flash_timer: 0,
+            invulnerable_timer: 0,
+            attack_timer: 120, // Give the player 2 seconds before the first shot
+            burst_timer: 180,
+            death_timer: 0,
+            frame_count: 0,
+        }
+    }
+
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x as i32, self.y as i32, BOSS_WIDTH as u32, BOSS_HEIGHT as u32)
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + BOSS_WIDTH as f32 / 2.0, self.y + BOSS_HEIGHT as f32 / 2.0)
+    }
+
+    pub fn hp_fraction(&self) -> f32 {
+        self.hp as f32 / self.max_hp as f32
+    }
+
+    pub fn is_defeated(&self) -> bool {
+        self.hp == 0
+    }
+
+    /// Death animation has played out (1.5 seconds)
+    pub fn is_finished(&self) -> bool {
+        self.is_defeated() && self.death_timer >= 90
+    }
+
+    fn phase_for_hp(&self) -> BossPhase {
+        let fraction = self.hp_fraction();
+        if fraction > 2.0 / 3.0 {
+            BossPhase::One
+        } else if fraction > 1.0 / 3.0 {
+            BossPhase::Two
+        } else {
+            BossPhase::Three
+        }
+    }
+
+    /// Apply damage and report what happened
+    pub fn take_damage(&mut self, amount: u32) -> BossEvent {
+        if self.invulnerable_timer > 0 || self.is_defeated() {
+            return BossEvent::None;
+        }
+
+        self.hp = self.hp.saturating_sub(amount);
+        self.flash_timer = 8;
+
+        if self.is_defeated() {
+            return BossEvent::Defeated;
+        }
+
+        let new_phase = self.phase_for_hp();
+        if new_phase != self.phase {
+            self.phase = new_phase;
+            self.invulnerable_timer = 60; // 1 second to enrage
+            self.attack_timer = 60;
+            return BossEvent::PhaseChanged(new_phase);
+        }
+
+        BossEvent::Damaged
+    }
+
+    /// Move and run the attack pattern. Newly fired projectiles are returned.
+    pub fn update(&mut self, paddle_center_x: f32, paddle_y: f32) -> Vec<BossProjectile> {
+        self.frame_count += 1;
+
+        if self.flash_timer > 0 {
+            self.flash_timer -= 1;
+        }
+        if self.invulnerable_timer > 0 {
+            self.invulnerable_timer -= 1;
+        }
+
+        // Defeated boss just shakes in place until the animation ends
+        if self.is_defeated() {
+            self.death_timer += 1;
+            return Vec::new();
+        }
+
+        // Sway left and right, faster in later phases
+        let sway_speed = match self.phase {
+            BossPhase::One => 0.010,
+            BossPhase::Two => 0.016,
+            BossPhase::Three => 0.024,
+        };
+        let travel = (WINDOW_WIDTH as i32 - BOSS_WIDTH) as f32 / 2.0 - 20.0;
+        let center_x = (WINDOW_WIDTH as i32 - BOSS_WIDTH) as f32 / 2.0;
+        self.x = center_x + (self.frame_count as f32 * sway_speed).sin() * travel;
+        self.y = BOSS_Y as f32 + (self.frame_count as f32 * 0.03).sin() * 8.0;
+
+        let mut fired = Vec::new();
+        if self.invulnerable_timer > 0 {
+            return fired;
+        }
+
+        let (cx, _) = self.center();
+        let muzzle_y = self.y + BOSS_HEIGHT as f32;
+
+        if self.attack_timer > 0 {
+            self.attack_timer -= 1;
+        } else {
+            // Aim at the paddle
+            let dx = paddle_center_x - cx;
+            let dy = paddle_y - muzzle_y;
+            let dist = (dx * dx + dy * dy).sqrt().max(1.0);
+
+            match self.phase {
+                BossPhase::One => {
+                    let speed = 4.0;
+                    fired.push(BossProjectile::new(cx, muzzle_y, dx / dist * speed, dy / dist * speed));
+                    self.attack_timer = 90;
+                }
+                BossPhase::Two => {
+                    let speed = 4.5;
+                    let base_angle = dy.atan2(dx);
+                    for spread in [-0.3f32, 0.0, 0.3] {
+                        let angle = base_angle + spread;
+                        fired.push(BossProjectile::new(cx, muzzle_y, angle.cos() * speed, angle.sin() * speed));
+                    }
+                    self.attack_timer = 80;
+                }
+                BossPhase::Three => {
+                    let speed = 5.5;
+                    // Small random error so it stays dodgeable
+                    let mut rng = rng::game_rng();
+                    let angle = dy.atan2(dx) + rng.gen_range(-0.1..0.1);
+                    fired.push(BossProjectile::new(cx, muzzle_y, angle.cos() * speed, angle.sin() * speed));
+                    self.attack_timer = 45;
+                }
+            }
+        }
+
+        if self.phase == BossPhase::Three {
+            if self.burst_timer > 0 {
+                self.burst_timer -= 1;
+            } else {
+                // Downward-facing ring burst
+                for k in 0..7 {
+                    let angle = (30.0 + k as f32 * 20.0).to_radians();
+                    fired.push(BossProjectile::new(cx, muzzle_y, angle.cos() * 3.5, angle.sin() * 3.5));
+                }
+                self.burst_timer = 150;
+            }
+        }
+
+        fired
+    }
+}
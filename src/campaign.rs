@@ -0,0 +1,233 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use crate::editor::{create_blocks_from_pattern, PatternData, PatternMeta};
+use crate::entities::*;
+use crate::modifiers::ModifierKind;
+use crate::validator::{has_errors, validate_file};
+
+const CAMPAIGN_FILE: &str = "campaign.json";
+
+/// Where a level's blocks come from
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum LevelBlocks {
+    Builtin(usize),  // Generator id: 1-9 handcrafted, 10+ random pattern seeded by the id
+    Pattern(String), // Pattern file saved by the level editor
+    Boss,            // No blocks, the boss takes the whole field
+    Empty,           // No blocks at all (a capsule rain bonus stage)
+}
+
+impl LevelBlocks {
+    pub fn create(&self) -> Vec<Block> {
+        match self {
+            LevelBlocks::Builtin(generator) => generate_blocks(*generator),
+            LevelBlocks::Pattern(path) => {
+                let file = crate::packs::resolve(path);
+                let issues = validate_file(&file);
+                for issue in &issues {
+                    eprintln!("{}: {}", path, issue);
+                }
+                if has_errors(&issues) {
+                    eprintln!("Campaign pattern {} is not playable, using a built-in level", path);
+                    return generate_blocks(1);
+                }
+                match PatternData::load_from_file(&file) {
+                    Ok(pattern) => create_blocks_from_pattern(&pattern),
+                    Err(e) => {
+                        eprintln!("Failed to load campaign pattern {}: {}", path, e);
+                        generate_blocks(1)
+                    }
+                }
+            }
+            LevelBlocks::Boss | LevelBlocks::Empty => Vec::new(),
+        }
+    }
+}
+
+/// Level backdrop
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum LevelBackground {
+    Image(String), // Image file
+    Theme(usize),  // Procedural theme: 7-9 handcrafted, any other id picks a seeded theme
+}
+
+/// Drop weights for capsules from destroyed blocks
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct BonusTable {
+    pub long_paddle: u32,
+    pub extra_ball: u32,
+    pub ghost_ball: u32,
+    pub rocket: u32,
+}
+
+impl Default for BonusTable {
+    fn default() -> Self {
+        BonusTable {
+            long_paddle: 50,
+            extra_ball: 25,
+            ghost_ball: 15,
+            rocket: 10,
+        }
+    }
+}
+
+impl BonusTable {
+    /// Weighted pick
+    pub fn roll(&self, rng: &mut impl Rng) -> BonusType {
+        let weights = [
+            (BonusType::LongPaddle, self.long_paddle),
+            (BonusType::ExtraBall, self.extra_ball),
+            (BonusType::GhostBall, self.ghost_ball),
+            (BonusType::Rocket, self.rocket),
+        ];
+        let total: u32 = weights.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return BonusType::LongPaddle;
+        }
+
+        let mut roll = rng.gen_range(0..total);
+        for (bonus_type, weight) in weights {
+            if roll < weight {
+                return bonus_type;
+            }
+            roll -= weight;
+        }
+        BonusType::LongPaddle
+    }
+}
+
+/// One campaign level, as stored in campaign.json
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CampaignLevel {
+    pub name: String,
+    pub blocks: LevelBlocks,
+    pub background: LevelBackground,
+    #[serde(default)]
+    pub music: Option<String>, // Plays instead of the shuffled playlist
+    #[serde(default)]
+    pub modifiers: Vec<ModifierKind>, // Added to the run's own modifiers for this level
+    #[serde(default)]
+    pub bonus_table: Option<BonusTable>,
+}
+
+impl CampaignLevel {
+    fn builtin(name: &str, generator: usize, background: LevelBackground) -> Self {
+        CampaignLevel {
+            name: name.to_string(),
+            blocks: LevelBlocks::Builtin(generator),
+            background,
+            music: None,
+            modifiers: Vec::new(),
+            bonus_table: None,
+        }
+    }
+}
+
+/// A bonus stage the speed portal can warp to, defined like a level.
+/// Its modifiers are not used; lost balls cost nothing there.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BonusStageDef {
+    #[serde(flatten)]
+    pub level: CampaignLevel,
+    #[serde(default)]
+    pub capsule_rain: bool, // Capsules pour down for the whole stage, drawn from bonus_table
+    #[serde(default)]
+    pub seconds: Option<u32>, // Stage length, instead of the portal setting
+}
+
+/// Capsule rain and a gold block field, taking turns
+pub fn default_bonus_stages() -> Vec<BonusStageDef> {
+    let mut gold_rush = CampaignLevel::builtin("Gold Rush", 0, LevelBackground::Theme(11));
+    gold_rush.blocks = LevelBlocks::Pattern("patterns/bonus/gold_rush.json".to_string());
+
+    let mut capsule_rain = CampaignLevel::builtin("Capsule Rain", 0, LevelBackground::Theme(12));
+    capsule_rain.blocks = LevelBlocks::Empty;
+    capsule_rain.bonus_table = Some(BonusTable { long_paddle: 60, extra_ball: 5, ghost_ball: 15, rocket: 20 });
+
+    vec![
+        BonusStageDef { level: capsule_rain, capsule_rain: true, seconds: None },
+        BonusStageDef { level: gold_rush, capsule_rain: false, seconds: None },
+    ]
+}
+
+/// Ordered campaign levels. Finishing the last one wins the campaign;
+/// infinite mode carries on after it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Campaign {
+    pub levels: Vec<CampaignLevel>,
+    #[serde(default = "default_bonus_stages")]
+    pub bonus_stages: Vec<BonusStageDef>,
+}
+
+impl Default for Campaign {
+    fn default() -> Self {
+        let image = |n: usize| LevelBackground::Image(format!("assets/background{}.png", n));
+        let mut boss = CampaignLevel::builtin("BOSS", 0, LevelBackground::Theme(10));
+        boss.blocks = LevelBlocks::Boss;
+
+        Campaign {
+            levels: vec![
+                CampaignLevel::builtin("Full House", 1, image(1)),
+                CampaignLevel::builtin("Checkerboard", 2, image(2)),
+                CampaignLevel::builtin("Stripes", 3, image(3)),
+                CampaignLevel::builtin("Columns", 4, image(4)),
+                CampaignLevel::builtin("Pyramid", 5, image(5)),
+                CampaignLevel::builtin("Diamond", 6, image(6)),
+                CampaignLevel::builtin("Spiral", 7, LevelBackground::Theme(7)),
+                CampaignLevel::builtin("Ripples", 8, LevelBackground::Theme(8)),
+                CampaignLevel::builtin("Lattice", 9, LevelBackground::Theme(9)),
+                boss,
+            ],
+            bonus_stages: default_bonus_stages(),
+        }
+    }
+}
+
+impl Campaign {
+    pub fn load() -> Self {
+        if Path::new(CAMPAIGN_FILE).exists() {
+            match fs::read_to_string(CAMPAIGN_FILE) {
+                Ok(content) => match serde_json::from_str::<Campaign>(&content) {
+                    Ok(campaign) if !campaign.levels.is_empty() => return campaign,
+                    Ok(_) => eprintln!("Campaign file has no levels, using the built-in campaign"),
+                    Err(e) => eprintln!("Failed to parse campaign: {}", e),
+                },
+                Err(e) => eprintln!("Failed to read campaign file: {}", e),
+            }
+        }
+
+        Self::default()
+    }
+
+    /// Campaign level by number (1-based), None past the end
+    pub fn level(&self, level: usize) -> Option<&CampaignLevel> {
+        level.checked_sub(1).and_then(|index| self.levels.get(index))
+    }
+
+    /// Number of the final level
+    pub fn last_level(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Bonus stage by name
+    pub fn bonus_stage(&self, name: &str) -> Option<&BonusStageDef> {
+        self.bonus_stages.iter().find(|stage| stage.level.name == name)
+    }
+}
+
+/// Metadata of a campaign level's pattern file, empty for generated levels
+pub fn level_meta(level: usize) -> PatternMeta {
+    match campaign().level(level).map(|def| &def.blocks) {
+        Some(LevelBlocks::Pattern(path)) => PatternData::load_from_file(&crate::packs::resolve(path))
+            .map(|pattern| pattern.meta)
+            .unwrap_or_default(),
+        _ => PatternMeta::default(),
+    }
+}
+
+/// Campaign of the selected pack (campaign.json for the built-in one)
+pub fn campaign() -> &'static Campaign {
+    &crate::packs::current().campaign
+}
@@ -0,0 +1,177 @@
+use crate::modifiers::ModifierKind;
+use crate::settings::Settings;
+
+pub const USAGE: &str = "\
+Usage: arkanoo [options]
+       arkanoo render-pattern IN [OUT.png | --ansi | --ascii]
+
+Start somewhere:
+  --level N            Start playing at campaign level N
+  --pattern PATH       Play a level file (as level 1, or the --level number)
+  --editor [PATH]      Open the level editor, optionally on a level file
+  --replay PATH        Watch a recorded run
+  --record PATH        Record the run to PATH (saved on exit)
+  --skip-splash        Go straight to the main menu
+
+Session settings (not saved):
+  --seed N             Seed the gameplay random generator
+  --windowed           Start in a window
+  --fullscreen         Start fullscreen
+  --resolution WxH     Window size, e.g. 1920x1080
+  --mute               No music or sound effects
+  --gravity            Turn on the Gravity modifier
+
+Tools:
+  --validate PATH      Check a level file, or every level file in a directory
+  --gym                Headless agent interface on stdin/stdout
+  --gym-port PORT      Headless agent interface on a local TCP port
+  --help               Show this help
+
+render-pattern draws a level file to a PNG thumbnail, or previews it in the
+terminal in color (--ansi, the default) or as level glyphs (--ascii).
+";
+
+/// Where `render-pattern` sends the preview
+#[derive(Debug, Clone, PartialEq)]
+pub enum PreviewOutput {
+    Png(String),
+    Ansi,
+    Ascii,
+}
+
+/// Command-line options. Anything not given keeps the saved settings.
+#[derive(Default, Debug)]
+pub struct Options {
+    pub level: Option<usize>,
+    pub pattern: Option<String>,
+    pub editor: Option<Option<String>>, // Some(None) opens an empty editor
+    pub replay: Option<String>,
+    pub record: Option<String>,
+    pub skip_splash: bool,
+    pub seed: Option<u64>,
+    pub fullscreen: Option<bool>,
+    pub resolution: Option<(u32, u32)>,
+    pub mute: bool,
+    pub gravity: bool,
+    pub validate: Option<String>,
+    pub gym: bool,
+    pub gym_port: Option<u16>,
+    pub help: bool,
+    pub render_pattern: Option<(String, PreviewOutput)>,
+}
+
+impl Options {
+    /// Parse the arguments after the program name
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Options::default();
+        if args.first().map(String::as_str) == Some("render-pattern") {
+            options.render_pattern = Some(parse_render_pattern(&args[1..])?);
+            return Ok(options);
+        }
+        let mut args = args.iter().peekable();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().cloned().ok_or(format!("{} needs a value", name));
+            match arg.as_str() {
+                "--level" => {
+                    let level = parse_number(&value("--level")?, "--level")?;
+                    if level == 0 {
+                        return Err("--level starts at 1".to_string());
+                    }
+                    options.level = Some(level as usize);
+                }
+                "--pattern" => options.pattern = Some(value("--pattern")?),
+                "--editor" => {
+                    // The level file is optional
+                    let path = args.next_if(|next| !next.starts_with("--")).cloned();
+                    options.editor = Some(path);
+                }
+                "--replay" => options.replay = Some(value("--replay")?),
+                "--record" => options.record = Some(value("--record")?),
+                "--skip-splash" => options.skip_splash = true,
+                "--seed" => options.seed = Some(parse_number(&value("--seed")?, "--seed")?),
+                "--windowed" => options.fullscreen = Some(false),
+                "--fullscreen" => options.fullscreen = Some(true),
+                "--resolution" => options.resolution = Some(parse_resolution(&value("--resolution")?)?),
+                "--mute" => options.mute = true,
+                "--gravity" => options.gravity = true,
+                "--validate" => options.validate = Some(value("--validate")?),
+                "--gym" => options.gym = true,
+                "--gym-port" => {
+                    let port = value("--gym-port")?;
+                    options.gym_port = Some(port.parse().map_err(|_| format!("--gym-port: '{}' is not a port number", port))?);
+                }
+                "--help" | "-h" => options.help = true,
+                other => return Err(format!("unknown option '{}'", other)),
+            }
+        }
+
+        if options.replay.is_some() && (options.level.is_some() || options.pattern.is_some() || options.seed.is_some() || options.gravity) {
+            return Err("--replay takes the level, seed and modifiers from the recording".to_string());
+        }
+        if options.replay.is_some() && options.record.is_some() {
+            return Err("--replay and --record cannot be combined".to_string());
+        }
+        if options.editor.is_some() && options.starts_game() {
+            return Err("--editor cannot be combined with starting a game".to_string());
+        }
+
+        Ok(options)
+    }
+
+    /// Override settings for this session; `Settings::save` keeps writing the old values
+    pub fn apply(&self, settings: &mut Settings) {
+        if let Some((width, height)) = self.resolution {
+            settings.pinned.window_size = Some((settings.window_width, settings.window_height));
+            settings.window_width = width;
+            settings.window_height = height;
+        }
+        if let Some(fullscreen) = self.fullscreen {
+            settings.pinned.fullscreen = Some(settings.fullscreen);
+            settings.fullscreen = fullscreen;
+        }
+        if self.mute {
+            settings.pinned.muted = Some((settings.music_muted, settings.sfx_muted));
+            settings.music_muted = true;
+            settings.sfx_muted = true;
+        }
+        if self.gravity {
+            settings.pinned.modifiers = Some(settings.modifiers.clone());
+            if !settings.modifiers.contains(&ModifierKind::Gravity) {
+                settings.modifiers.push(ModifierKind::Gravity);
+            }
+        }
+    }
+
+    /// Whether to skip the title screen and start playing
+    pub fn starts_game(&self) -> bool {
+        self.level.is_some() || self.pattern.is_some() || self.replay.is_some() || self.record.is_some()
+    }
+}
+
+/// `render-pattern IN [OUT.png | --ansi | --ascii]`
+fn parse_render_pattern(args: &[String]) -> Result<(String, PreviewOutput), String> {
+    match args {
+        [input] => Ok((input.clone(), PreviewOutput::Ansi)),
+        [input, flag] if flag == "--ansi" => Ok((input.clone(), PreviewOutput::Ansi)),
+        [input, flag] if flag == "--ascii" => Ok((input.clone(), PreviewOutput::Ascii)),
+        [input, output] if !output.starts_with("--") => Ok((input.clone(), PreviewOutput::Png(output.clone()))),
+        _ => Err("render-pattern takes a level file and an output .png, --ansi or --ascii".to_string()),
+    }
+}
+
+fn parse_number(value: &str, name: &str) -> Result<u64, String> {
+    value.parse().map_err(|_| format!("{}: '{}' is not a number", name, value))
+}
+
+/// "1920x1080"
+fn parse_resolution(value: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("--resolution: '{}' is not WIDTHxHEIGHT", value);
+    let (width, height) = value.split_once('x').ok_or_else(invalid)?;
+    let width: u32 = width.parse().map_err(|_| invalid())?;
+    let height: u32 = height.parse().map_err(|_| invalid())?;
+    if width < 320 || height < 180 {
+        return Err(format!("--resolution: {}x{} is too small", width, height));
+    }
+    Ok((width, height))
+}
@@ -0,0 +1,156 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::modifiers::ModifierKind;
+
+const DAILY_SCORES_FILE: &str = "daily_scores.json";
+
+/// A daily run is this many generated levels
+pub const DAILY_LEVELS: usize = 5;
+
+/// The counted run of one day
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DailyScore {
+    pub date: String, // YYYY-MM-DD (UTC)
+    pub score: u32,
+    pub levels: usize, // Levels cleared
+    #[serde(default)]
+    pub finished: bool, // Cleared all DAILY_LEVELS
+}
+
+/// Local history of daily scores, oldest first
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DailyHistory {
+    pub days: Vec<DailyScore>,
+}
+
+impl DailyHistory {
+    pub fn load() -> Self {
+        if Path::new(DAILY_SCORES_FILE).exists() {
+            match fs::read_to_string(DAILY_SCORES_FILE) {
+                Ok(content) => match serde_json::from_str(&content) {
+                    Ok(history) => return history,
+                    Err(e) => eprintln!("Failed to parse daily scores: {}", e),
+                },
+                Err(e) => eprintln!("Failed to read daily scores file: {}", e),
+            }
+        }
+
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(DAILY_SCORES_FILE, json)?;
+        Ok(())
+    }
+
+    pub fn day(&self, date: &str) -> Option<&DailyScore> {
+        self.days.iter().find(|day| day.date == date)
+    }
+
+    pub fn best(&self) -> Option<&DailyScore> {
+        self.days.iter().max_by_key(|day| day.score)
+    }
+
+    /// Add or replace a day's entry
+    fn set(&mut self, entry: DailyScore) {
+        match self.days.iter_mut().find(|day| day.date == entry.date) {
+            Some(day) => *day = entry,
+            None => self.days.push(entry),
+        }
+    }
+}
+
+/// Today's challenge: everything random in the run comes from the date, so
+/// every player gets the same levels, modifiers, capsules and backgrounds
+pub struct DailyChallenge {
+    pub date: String,
+    pub seed: u64,
+    pub modifiers: Vec<ModifierKind>,
+    pub scored: bool, // Only the first attempt of the day counts, later runs are practice
+    pub counted: Option<DailyScore>, // Today's counted run, when this one is practice
+    pub best: Option<DailyScore>, // Best day before this run
+}
+
+impl DailyChallenge {
+    /// Today's challenge, not started yet
+    pub fn today() -> Self {
+        Self::for_day(today())
+    }
+
+    /// The run is starting. The first attempt of the day is written to the
+    /// history straight away, so quitting a bad run does not give another try.
+    pub fn start(&mut self) {
+        let history = DailyHistory::load();
+        self.counted = history.day(&self.date).cloned();
+        self.best = history.best().cloned();
+        self.scored = self.counted.is_none();
+        self.record(0, 0, false);
+    }
+
+    /// The challenge of a day, counted from 1970-01-01
+    pub fn for_day(day: u64) -> Self {
+        let seed = (day ^ 0xDA17).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let modifiers = vec![ModifierKind::ALL[rng.gen_range(0..ModifierKind::ALL.len())]];
+
+        DailyChallenge {
+            date: format_date(day),
+            seed,
+            modifiers,
+            scored: false,
+            counted: None,
+            best: None,
+        }
+    }
+
+    /// Generator number for a level of the run: random layouts (10 and up)
+    /// that only repeat for the same day. Kept small enough for a 32-bit usize.
+    pub fn layout(&self, level: usize) -> usize {
+        (10 + (self.seed % 100_000_000) * DAILY_LEVELS as u64 + level as u64) as usize
+    }
+
+    /// Infinite mode level whose special block odds a daily level uses (10, 15, ... 30)
+    pub fn difficulty(&self, level: usize) -> usize {
+        10 + (level - 1) * 5
+    }
+
+    /// Save the counted run's progress (practice runs are not kept)
+    pub fn record(&self, score: u32, levels: usize, finished: bool) {
+        if !self.scored {
+            return;
+        }
+
+        let mut history = DailyHistory::load();
+        history.set(DailyScore { date: self.date.clone(), score, levels, finished });
+        if let Err(e) = history.save() {
+            eprintln!("Failed to save daily scores: {}", e);
+        }
+    }
+}
+
+/// Days since 1970-01-01, in UTC so everyone shares the same day
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86_400)
+}
+
+/// Day number to YYYY-MM-DD (proleptic Gregorian calendar)
+fn format_date(day: u64) -> String {
+    // Shift the epoch to 0000-03-01 so leap days fall at the end of a year
+    let days = day as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153; // 0 = March
+    let day_of_month = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day_of_month)
+}
@@ -1,86 +1,154 @@
 use crate::entities::*;
 use crate::menu::Button;
+use crate::validator::{has_errors, validate_file, validate_pattern, Severity};
 use sdl2::rect::Rect;
+use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::Path;
+
+/// Current level file version (v1 is the ASCII .txt format)
+pub const PATTERN_FORMAT_VERSION: u32 = 2;
+
+/// Level metadata, only stored by the v2 format
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[serde(default)]
+pub struct PatternMeta {
+    pub title: String,
+    pub author: String,
+    pub par_seconds: Option<u32>, // Target clear time for the time bonus
+    pub music: Option<String>,    // Track played with this level
+}
 
-/// Serializable pattern data structure with ASCII format
+/// One block of a pattern
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PatternCell {
+    pub color: Color,
+    #[serde(rename = "type")]
+    pub block_type: BlockType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits: Option<u8>, // Overrides the block type's hit points
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop: Option<BonusType>, // Capsule always dropped when destroyed
+}
+
+impl PatternCell {
+    pub fn new(color: Color, block_type: BlockType) -> Self {
+        PatternCell { color, block_type, hits: None, drop: None }
+    }
+}
+
+/// Pattern data, loaded from either level format
 #[derive(Clone)]
 pub struct PatternData {
     pub name: String,
-    // Store (color_index, block_type_char)
-    // 255 for empty
-    // For blocks: (0-9, 'N'|'I'|'E'|'U')
-    // We'll pack this into a custom struct or just use a more complex grid
-    // Let's use a struct for grid cells to be clean
-    pub grid: [[PatternCell; BLOCK_COLS]; BLOCK_ROWS],
+    pub meta: PatternMeta,
+    pub layout: GridLayout,
+    pub grid: Vec<Vec<Option<PatternCell>>>, // layout.rows x layout.cols
 }
 
-#[derive(Clone, Copy, PartialEq)]
-pub struct PatternCell {
-    pub color_index: u8, // 255 = empty
-    pub block_type: BlockType,
+/// v2 file: metadata plus the non-empty cells
+#[derive(Serialize, Deserialize)]
+struct PatternFile {
+    version: u32,
+    #[serde(default)]
+    meta: PatternMeta,
+    #[serde(default)]
+    grid: GridLayout,
+    cells: Vec<PlacedCell>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PlacedCell {
+    row: usize,
+    col: usize,
+    #[serde(flatten)]
+    cell: PatternCell,
 }
 
 impl PatternData {
     pub fn new(name: String) -> Self {
+        Self::with_layout(name, GridLayout::default())
+    }
+
+    pub fn with_layout(name: String, layout: GridLayout) -> Self {
         PatternData {
             name,
-            grid: [[PatternCell { color_index: 255, block_type: BlockType::Normal }; BLOCK_COLS]; BLOCK_ROWS],
+            meta: PatternMeta::default(),
+            layout,
+            grid: vec![vec![None; layout.cols]; layout.rows],
         }
     }
 
-    /// Save pattern to ASCII format
-    /// * = empty space
-    ///   0-5 = Normal blocks with color index
-    ///   6 = Ice block
-    ///   7 = Explosive block
-    ///   8 = Undestroyable block
+    /// Save pattern in the v2 JSON format as `<dir>/<name>.json`
     pub fn save_to_file(&self, dir: &str) -> Result<(), Box<dyn std::error::Error>> {
         fs::create_dir_all(dir)?;
-        
-        let filename = format!("{}/{}.txt", dir, self.name);
-        let mut content = String::new();
-        
-        // Header with pattern name
-        content.push_str(&format!("# Pattern: {}\n", self.name));
-        content.push_str("# * = empty, 0-5 = normal, 6 = Ice, 7 = Explosive, 8 = Undestroyable\n\n");
-        
-        // Write grid
-        for row in 0..BLOCK_ROWS {
-            for col in 0..BLOCK_COLS {
-                let cell = self.grid[row][col];
-                let ch = if cell.color_index == 255 {
-                    '*'
-                } else {
-                    // 0-5 for normal blocks, 6-8 for special blocks
-                    match cell.block_type {
-                        BlockType::Ice => '6',
-                        BlockType::Explosive => '7',
-                        BlockType::Undestroyable => '8',
-                        BlockType::Normal => char::from_digit(cell.color_index as u32, 10).unwrap_or('0'),
-                    }
-                };
-                content.push(ch);
+
+        let mut cells = Vec::new();
+        for (row, cols) in self.grid.iter().enumerate() {
+            for (col, cell) in cols.iter().enumerate() {
+                if let Some(cell) = cell {
+                    cells.push(PlacedCell { row, col, cell: *cell });
+                }
             }
-            content.push('\n');
         }
-        
-        fs::write(filename, content)?;
+        let file = PatternFile {
+            version: PATTERN_FORMAT_VERSION,
+            meta: self.meta.clone(),
+            grid: self.layout,
+            cells,
+        };
+
+        let filename = format!("{}/{}.json", dir, self.name);
+        fs::write(filename, serde_json::to_string_pretty(&file)?)?;
         Ok(())
     }
 
-    /// Load pattern from ASCII format
+    /// Load a pattern: v2 `.json`, or the ASCII `.txt` format
     pub fn load_from_file(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let content = fs::read_to_string(path)?;
-        let mut grid = [[PatternCell { color_index: 255, block_type: BlockType::Normal }; BLOCK_COLS]; BLOCK_ROWS];
-        
+
         // Extract pattern name from path
-        let name = std::path::Path::new(path)
+        let name = Path::new(path)
             .file_stem()
             .and_then(|s| s.to_str())
             .unwrap_or("pattern")
             .to_string();
-        
+
+        if Path::new(path).extension().and_then(|s| s.to_str()) == Some("json") {
+            Self::parse_json(name, &content)
+        } else {
+            Ok(Self::parse_ascii(name, &content))
+        }
+    }
+
+    fn parse_json(name: String, content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let file: PatternFile = serde_json::from_str(content)?;
+        if file.version > PATTERN_FORMAT_VERSION {
+            return Err(format!("level format v{} is newer than this game (v{})", file.version, PATTERN_FORMAT_VERSION).into());
+        }
+
+        file.grid.check()?;
+
+        let mut pattern = PatternData::with_layout(name, file.grid);
+        pattern.meta = file.meta;
+        for placed in file.cells {
+            if placed.row >= file.grid.rows || placed.col >= file.grid.cols {
+                return Err(format!("cell ({}, {}) is outside the {}x{} grid", placed.row, placed.col, file.grid.cols, file.grid.rows).into());
+            }
+            pattern.grid[placed.row][placed.col] = Some(placed.cell);
+        }
+        Ok(pattern)
+    }
+
+    /// ASCII format (v1), always on the standard grid
+    /// * = empty space
+    ///   0-5 = Normal blocks with color index
+    ///   6 = Ice block
+    ///   7 = Explosive block
+    ///   8 = Undestroyable block
+    fn parse_ascii(name: String, content: &str) -> Self {
+        let mut pattern = PatternData::new(name);
+
         let mut row = 0;
         for line in content.lines() {
             // Skip comments and empty lines
@@ -93,37 +161,57 @@ impl PatternData {
             }
             
             for (col, ch) in line.chars().take(BLOCK_COLS).enumerate() {
-                let (color_index, block_type) = match ch {
-                    '*' => (255, BlockType::Normal),
-                    '0'..='5' => (ch.to_digit(10).unwrap() as u8, BlockType::Normal),
-                    '6' => (0, BlockType::Ice),
-                    '7' => (0, BlockType::Explosive),
-                    '8' => (0, BlockType::Undestroyable),
+                let block_type = match ch {
+                    '0'..='5' => BlockType::Normal,
+                    '6' => BlockType::Ice,
+                    '7' => BlockType::Explosive,
+                    '8' => BlockType::Undestroyable,
                     // Backward compatibility with old format
-                    'I' => (0, BlockType::Ice),
-                    'E' => (0, BlockType::Explosive),
-                    'U' => (0, BlockType::Undestroyable),
-                    _ => (255, BlockType::Normal),
+                    'I' => BlockType::Ice,
+                    'E' => BlockType::Explosive,
+                    'U' => BlockType::Undestroyable,
+                    _ => continue,
                 };
-                grid[row][col] = PatternCell { color_index, block_type };
+                // Special blocks have no color digit of their own
+                let color_index = ch.to_digit(10).filter(|&d| d <= 5).unwrap_or(0) as usize;
+                pattern.grid[row][col] = Some(PatternCell::new(BLOCK_COLORS[color_index % BLOCK_COLORS.len()], block_type));
             }
             
             row += 1;
         }
         
-        Ok(PatternData { name, grid })
+        pattern
     }
+
+    pub fn block_count(&self) -> usize {
+        self.grid.iter().flatten().filter(|cell| cell.is_some()).count()
+    }
+}
+
+/// An ASCII pattern that also has a v2 file of the same name (the v2 file wins)
+fn has_json_sibling(path: &Path) -> bool {
+    path.extension().and_then(|s| s.to_str()) == Some("txt") && path.with_extension("json").exists()
 }
 
-/// Load all patterns from a directory
+/// Load all playable patterns from a directory (both formats, one per name).
+/// Files the validator finds errors in are reported and left out.
 pub fn load_all_patterns(dir: &str) -> Vec<PatternData> {
     let mut patterns = Vec::new();
     
     if let Ok(entries) = fs::read_dir(dir) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("txt") {
-                if let Ok(pattern) = PatternData::load_from_file(path.to_str().unwrap()) {
+            if matches!(path.extension().and_then(|s| s.to_str()), Some("txt") | Some("json")) && !has_json_sibling(&path) {
+                let path = path.to_string_lossy().to_string();
+                let issues = validate_file(&path);
+                for issue in &issues {
+                    eprintln!("{}: {}", path, issue);
+                }
+                if has_errors(&issues) {
+                    eprintln!("Pattern {} is not playable, leaving it out of infinite mode", path);
+                    continue;
+                }
+                if let Ok(pattern) = PatternData::load_from_file(&path) {
                     patterns.push(pattern);
                 }
             }
@@ -133,21 +221,50 @@ pub fn load_all_patterns(dir: &str) -> Vec<PatternData> {
     patterns
 }
 
+/// Convert every ASCII pattern in a directory to the v2 format.
+/// The old file is kept as `<name>.txt.bak`. An existing `.json` is never
+/// overwritten - those names are skipped. Returns the upgraded count and the skipped names.
+pub fn upgrade_patterns(dir: &str) -> Result<(usize, Vec<String>), Box<dyn std::error::Error>> {
+    let mut upgraded = 0;
+    let mut skipped = Vec::new();
+
+    for entry in fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("txt") {
+            continue;
+        }
+        if has_json_sibling(&path) {
+            skipped.push(path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string());
+            continue;
+        }
+        let path_str = path.to_string_lossy().to_string();
+        let mut pattern = PatternData::load_from_file(&path_str)?;
+        if pattern.meta.title.is_empty() {
+            pattern.meta.title = pattern.name.clone();
+        }
+        pattern.save_to_file(dir)?;
+        fs::rename(&path, format!("{}.bak", path_str))?;
+        upgraded += 1;
+    }
+
+    skipped.sort();
+    Ok((upgraded, skipped))
+}
+
 /// Convert pattern data to blocks for the game
 pub fn create_blocks_from_pattern(pattern: &PatternData) -> Vec<Block> {
     let mut blocks = Vec::new();
-    let total_blocks_width = BLOCK_COLS as i32 * BLOCK_WIDTH;
-    let offset_x = (WINDOW_WIDTH as i32 - total_blocks_width) / 2;
-
-    for row in 0..BLOCK_ROWS {
-        for col in 0..BLOCK_COLS {
-            let cell = pattern.grid[row][col];
-            if cell.color_index != 255 {
-                let x = offset_x + col as i32 * BLOCK_WIDTH;
-                let y = BLOCK_OFFSET_Y + row as i32 * BLOCK_HEIGHT;
-                let color_idx = (cell.color_index as usize) % BLOCK_COLORS.len();
-                let color = BLOCK_COLORS[color_idx];
-                blocks.push(Block::new(x, y, color, cell.block_type));
+
+    for (row, cols) in pattern.grid.iter().enumerate() {
+        for (col, cell) in cols.iter().enumerate() {
+            if let Some(cell) = cell {
+                let mut block = pattern.layout.block(row, col, cell.color, cell.block_type);
+                if let Some(hits) = cell.hits {
+                    block.health = hits.max(1);
+                    block.max_health = block.health;
+                }
+                block.drop = cell.drop;
+                blocks.push(block);
             }
         }
     }
@@ -186,6 +303,8 @@ pub struct LevelEditor {
     pub blocks: Vec<Block>,
     pub selected_color_index: usize,
     pub pattern_name: String,
+    pub pattern_meta: PatternMeta, // Kept from the loaded pattern, written back on save
+    pub layout: GridLayout,
     pub pattern_name_editing: bool,
     pub save_button: Button,
     pub clear_button: Button,
@@ -232,6 +351,8 @@ impl LevelEditor {
             blocks: Vec::new(),
             selected_color_index: 0,
             pattern_name: String::from("my_pattern"),
+            pattern_meta: PatternMeta::default(),
+            layout: GridLayout::default(),
             pattern_name_editing: false,
             save_button: Button::new(start_x, button_y, button_width, button_height, "Save (S)"),
             clear_button: Button::new(start_x + spacing, button_y, button_width, button_height, "Clear (C)"),
@@ -261,7 +382,7 @@ impl LevelEditor {
         if let Ok(entries) = fs::read_dir("patterns") {
             for entry in entries.flatten() {
                 if let Ok(path) = entry.path().canonicalize() {
-                    if path.extension().and_then(|s| s.to_str()) == Some("txt") {
+                    if matches!(path.extension().and_then(|s| s.to_str()), Some("txt") | Some("json")) {
                         if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
                             self.available_patterns.push(name.to_string());
                         }
@@ -271,41 +392,32 @@ impl LevelEditor {
         }
         
         self.available_patterns.sort();
+        self.available_patterns.dedup(); // Same name in both formats
         self.selected_pattern_index = 0;
     }
 
     pub fn load_pattern(&mut self, name: &str) -> Result<(), String> {
-        let path = format!("patterns/{}.txt", name);
-        
-        match PatternData::load_from_file(&path) {
+        // The v2 file wins over an ASCII one of the same name
+        let json_path = format!("patterns/{}.json", name);
+        let path = if Path::new(&json_path).exists() { json_path } else { format!("patterns/{}.txt", name) };
+        self.load_pattern_file(&path)
+    }
+
+    /// Load a level file from anywhere; saving still goes to patterns/
+    pub fn load_pattern_file(&mut self, path: &str) -> Result<(), String> {
+        let name = Path::new(path).file_stem().and_then(|s| s.to_str()).unwrap_or("pattern").to_string();
+
+        match PatternData::load_from_file(path) {
             Ok(pattern) => {
-                // Convert pattern data to blocks
-                self.blocks.clear();
-                
-                for row in 0..BLOCK_ROWS {
-                    for col in 0..BLOCK_COLS {
-                        let cell = pattern.grid[row][col];
-                        if cell.color_index != 255 {
-                            let total_blocks_width = BLOCK_COLS as i32 * BLOCK_WIDTH;
-                            let offset_x = (WINDOW_WIDTH as i32 - total_blocks_width) / 2;
-                            let x = offset_x + col as i32 * BLOCK_WIDTH;
-                            let y = BLOCK_OFFSET_Y + row as i32 * BLOCK_HEIGHT;
-                            
-                            self.blocks.push(Block {
-                                x,
-                                y,
-                                active: true,
-                                color: BLOCK_COLORS[cell.color_index as usize % BLOCK_COLORS.len()],
-                                block_type: cell.block_type,
-                                health: 1,
-                                max_health: 1,
-                            });
-                        }
-                    }
-                }
-                
+                self.blocks = create_blocks_from_pattern(&pattern);
+                self.layout = pattern.layout;
+                self.pattern_meta = pattern.meta.clone();
                 self.pattern_name = pattern.name;
-                self.show_message(format!("Loaded pattern: {}", name));
+                let issues = validate_file(path);
+                match issues.first() {
+                    Some(issue) => self.show_message(format!("Loaded {} - {} issue(s), {}", name, issues.len(), issue)),
+                    None => self.show_message(format!("Loaded pattern: {}", name)),
+                }
                 Ok(())
             }
             Err(e) => {
@@ -316,51 +428,90 @@ impl LevelEditor {
     }
 
     pub fn save_pattern(&mut self) -> Result<(), String> {
-        let mut pattern = PatternData::new(self.pattern_name.clone());
-        
-        let total_blocks_width = BLOCK_COLS as i32 * BLOCK_WIDTH;
-        let offset_x = (WINDOW_WIDTH as i32 - total_blocks_width) / 2;
+        let mut pattern = PatternData::with_layout(self.pattern_name.clone(), self.layout);
 
-        // Convert blocks to grid with color indices
+        // Convert blocks to grid cells, keeping their color and attributes
         for block in &self.blocks {
             if block.active {
-                let col = ((block.x - offset_x) / BLOCK_WIDTH) as usize;
-                let row = ((block.y - BLOCK_OFFSET_Y) / BLOCK_HEIGHT) as usize;
-                
-                if row < BLOCK_ROWS && col < BLOCK_COLS {
-                    // Find color index
-                    for (idx, &color) in BLOCK_COLORS.iter().enumerate() {
-                        if block.color.r == color.r && block.color.g == color.g && block.color.b == color.b {
-                            pattern.grid[row][col] = PatternCell {
-                                color_index: idx as u8,
-                                block_type: block.block_type,
-                            };
-                            break;
-                        }
+                if let Some((row, col)) = self.layout.cell_at(block.x, block.y) {
+                    let mut cell = PatternCell::new(block.color, block.block_type);
+                    if block.max_health != Block::new(0, 0, block.color, block.block_type).max_health {
+                        cell.hits = Some(block.max_health);
                     }
+                    cell.drop = block.drop;
+                    pattern.grid[row][col] = Some(cell);
                 }
             }
         }
 
-        // Validate: ensure at least some blocks
-        let block_count = pattern.grid.iter()
-            .flatten()
-            .filter(|&&cell| cell.color_index != 255)
-            .count();
-            
-        if block_count == 0 {
-            return Err("Pattern must have at least one block".to_string());
+        // Refuse levels that cannot be finished
+        let issues = validate_pattern(&pattern);
+        if let Some(issue) = issues.iter().find(|issue| issue.severity == Severity::Error) {
+            return Err(format!("Not saved - {}", issue));
+        }
+
+        pattern.meta = self.pattern_meta.clone();
+        if pattern.meta.title.is_empty() {
+            pattern.meta.title = self.pattern_name.clone();
         }
 
         match pattern.save_to_file("patterns") {
             Ok(_) => {
-                self.show_message(format!("Saved: {}.txt", self.pattern_name));
+                self.show_message(format!("Saved: {}.json", self.pattern_name));
                 Ok(())
             }
             Err(e) => Err(format!("Failed to save: {}", e)),
         }
     }
 
+    /// Convert the ASCII patterns in patterns/ to the v2 format
+    pub fn upgrade_patterns(&mut self) {
+        match upgrade_patterns("patterns") {
+            Ok((0, skipped)) if skipped.is_empty() => self.show_message("No .txt patterns to upgrade".to_string()),
+            Ok((count, skipped)) if skipped.is_empty() => {
+                self.show_message(format!("Upgraded {} pattern(s) to v{}", count, PATTERN_FORMAT_VERSION))
+            }
+            Ok((count, skipped)) => self.show_message(format!(
+                "Upgraded {} pattern(s), skipped {} (a .json already exists): {}",
+                count,
+                skipped.len(),
+                skipped.join(", ")
+            )),
+            Err(e) => self.show_message(format!("Upgrade failed: {}", e)),
+        }
+        self.discover_patterns();
+    }
+
+    /// Switch to the next grid preset, keeping the blocks whose cell still exists
+    pub fn next_layout(&mut self) {
+        let presets = &GridLayout::PRESETS;
+        let index = presets.iter().position(|layout| *layout == self.layout).map_or(0, |i| (i + 1) % presets.len());
+        let layout = presets[index];
+
+        let old_layout = self.layout;
+        let mut kept = Vec::new();
+        for block in &self.blocks {
+            if let Some((row, col)) = old_layout.cell_at(block.x, block.y) {
+                if row < layout.rows && col < layout.cols {
+                    let mut moved = layout.block(row, col, block.color, block.block_type);
+                    moved.health = block.health;
+                    moved.max_health = block.max_health;
+                    moved.drop = block.drop;
+                    kept.push(moved);
+                }
+            }
+        }
+        let dropped = self.blocks.len() - kept.len();
+        self.blocks = kept;
+        self.layout = layout;
+
+        let mut message = format!("Grid: {}x{} of {}x{} blocks", layout.cols, layout.rows, layout.block_width, layout.block_height);
+        if dropped > 0 {
+            message.push_str(&format!(" ({} removed)", dropped));
+        }
+        self.show_message(message);
+    }
+
     pub fn request_clear(&mut self) {
         if !self.blocks.is_empty() {
             self.confirm_clear = true;
@@ -380,22 +531,11 @@ impl LevelEditor {
     }
 
     pub fn add_block_at(&mut self, mouse_x: i32, mouse_y: i32) {
-        let total_blocks_width = BLOCK_COLS as i32 * BLOCK_WIDTH;
-        let offset_x = (WINDOW_WIDTH as i32 - total_blocks_width) / 2;
-
         // Calculate grid position
-        if mouse_x < offset_x || mouse_x >= offset_x + total_blocks_width {
-            return;
-        }
-        if mouse_y < BLOCK_OFFSET_Y || mouse_y >= BLOCK_OFFSET_Y + (BLOCK_ROWS as i32 * BLOCK_HEIGHT) {
+        let Some((row, col)) = self.layout.cell_at(mouse_x, mouse_y) else {
             return;
-        }
-
-        let col = (mouse_x - offset_x) / BLOCK_WIDTH;
-        let row = (mouse_y - BLOCK_OFFSET_Y) / BLOCK_HEIGHT;
-
-        let x = offset_x + col * BLOCK_WIDTH;
-        let y = BLOCK_OFFSET_Y + row * BLOCK_HEIGHT;
+        };
+        let (x, y) = self.layout.cell_position(row, col);
 
         // Check if block already exists at this position
         let block_exists = self.blocks.iter().any(|b| b.x == x && b.y == y);
@@ -409,27 +549,16 @@ impl LevelEditor {
                 8 => (BLOCK_COLORS[0], BlockType::Undestroyable),
                 _ => (BLOCK_COLORS[self.selected_color_index % BLOCK_COLORS.len()], BlockType::Normal),
             };
-            self.blocks.push(Block::new(x, y, color, block_type));
+            self.blocks.push(self.layout.block(row, col, color, block_type));
         }
     }
 
     pub fn remove_block_at(&mut self, mouse_x: i32, mouse_y: i32) {
-        let total_blocks_width = BLOCK_COLS as i32 * BLOCK_WIDTH;
-        let offset_x = (WINDOW_WIDTH as i32 - total_blocks_width) / 2;
-
         // Check bounds
-        if mouse_x < offset_x || mouse_x >= offset_x + total_blocks_width {
-            return;
-        }
-        if mouse_y < BLOCK_OFFSET_Y || mouse_y >= BLOCK_OFFSET_Y + (BLOCK_ROWS as i32 * BLOCK_HEIGHT) {
+        let Some((row, col)) = self.layout.cell_at(mouse_x, mouse_y) else {
             return;
-        }
-
-        let col = (mouse_x - offset_x) / BLOCK_WIDTH;
-        let row = (mouse_y - BLOCK_OFFSET_Y) / BLOCK_HEIGHT;
-
-        let x = offset_x + col * BLOCK_WIDTH;
-        let y = BLOCK_OFFSET_Y + row * BLOCK_HEIGHT;
+        };
+        let (x, y) = self.layout.cell_position(row, col);
 
         self.blocks.retain(|b| !(b.x == x && b.y == y));
     }
@@ -0,0 +1,152 @@
+use crate::entities::Color;
+
+/// Timed power-ups and penalties held by a paddle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    LongPaddle,
+    GhostBall,
+    Rockets,
+    Shrink,
+}
+
+/// What catching an effect that is already active does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackRule {
+    Refresh,        // Restart the timer
+    Extend,         // Add a full duration to the time left
+    Intensity(u32), // One more level (up to the max) and restart the timer
+}
+
+impl EffectKind {
+    /// Frames the effect lasts; None lasts until used up
+    pub fn duration(self) -> Option<u32> {
+        match self {
+            EffectKind::LongPaddle => Some(300), // 5 seconds at 60 FPS
+            EffectKind::GhostBall => Some(600),  // 10 seconds
+            EffectKind::Rockets => None,         // One stack per rocket
+            EffectKind::Shrink => Some(480),     // 8 seconds
+        }
+    }
+
+    pub fn stack_rule(self) -> StackRule {
+        match self {
+            EffectKind::LongPaddle => StackRule::Intensity(3),
+            EffectKind::GhostBall => StackRule::Extend,
+            EffectKind::Rockets => StackRule::Intensity(99),
+            EffectKind::Shrink => StackRule::Refresh,
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            EffectKind::LongPaddle => Color::new(100, 255, 100), // Green
+            EffectKind::GhostBall => Color::new(200, 200, 200),  // Grey
+            EffectKind::Rockets => Color::new(255, 165, 0),      // Orange
+            EffectKind::Shrink => Color::new(160, 40, 200),      // Purple
+        }
+    }
+}
+
+pub struct Effect {
+    pub kind: EffectKind,
+    pub remaining: u32, // Frames left (unused for untimed effects)
+    pub total: u32,     // Frames the current timer started from, for the HUD bar
+    pub stacks: u32,
+}
+
+impl Effect {
+    /// Fraction of time left, 1.0 for untimed effects
+    pub fn progress(&self) -> f32 {
+        if self.kind.duration().is_none() || self.total == 0 {
+            1.0
+        } else {
+            self.remaining as f32 / self.total as f32
+        }
+    }
+}
+
+/// Every effect currently running, in the order they started
+#[derive(Default)]
+pub struct ActiveEffects {
+    effects: Vec<Effect>,
+}
+
+impl ActiveEffects {
+    /// Start an effect or stack it onto the running one. Returns true when it just started.
+    pub fn add(&mut self, kind: EffectKind) -> bool {
+        let duration = kind.duration().unwrap_or(0);
+
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            match kind.stack_rule() {
+                StackRule::Refresh => {
+                    effect.remaining = duration;
+                    effect.total = duration;
+                }
+                StackRule::Extend => {
+                    effect.remaining += duration;
+                    effect.total = effect.remaining;
+                }
+                StackRule::Intensity(max) => {
+                    effect.stacks = (effect.stacks + 1).min(max);
+                    effect.remaining = duration;
+                    effect.total = duration;
+                }
+            }
+            return false;
+        }
+
+        self.effects.push(Effect {
+            kind,
+            remaining: duration,
+            total: duration,
+            stacks: 1,
+        });
+        true
+    }
+
+    /// Count down timed effects. Returns the ones that just ran out.
+    pub fn tick(&mut self) -> Vec<EffectKind> {
+        let mut ended = Vec::new();
+        self.effects.retain_mut(|effect| {
+            if effect.kind.duration().is_none() {
+                return true;
+            }
+            effect.remaining = effect.remaining.saturating_sub(1);
+            if effect.remaining == 0 {
+                ended.push(effect.kind);
+                return false;
+            }
+            true
+        });
+        ended
+    }
+
+    /// Use up one stack (a rocket). Returns false if there was none.
+    pub fn consume(&mut self, kind: EffectKind) -> bool {
+        let Some(index) = self.effects.iter().position(|e| e.kind == kind) else {
+            return false;
+        };
+        self.effects[index].stacks -= 1;
+        if self.effects[index].stacks == 0 {
+            self.effects.remove(index);
+        }
+        true
+    }
+
+    pub fn is_active(&self, kind: EffectKind) -> bool {
+        self.effects.iter().any(|e| e.kind == kind)
+    }
+
+    /// Stack count, 0 when not active
+    pub fn stacks(&self, kind: EffectKind) -> u32 {
+        self.effects.iter().find(|e| e.kind == kind).map_or(0, |e| e.stacks)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Effect> {
+        self.effects.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+}
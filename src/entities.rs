@@ -1,4 +1,8 @@
 use sdl2::rect::Rect;
+use serde::{Deserialize, Serialize};
+use crate::effects::{ActiveEffects, EffectKind};
+use crate::modifiers::Modifiers;
+use crate::rng;
 
 
 /// Game constants
@@ -13,8 +17,9 @@ pub const BLOCK_HEIGHT: i32 = 20;
 pub const BLOCK_ROWS: usize = 10;
 pub const BLOCK_COLS: usize = 20;
 pub const BLOCK_OFFSET_Y: i32 = 80;
+pub const EXPLOSION_RADIUS: f32 = 60.0; // Blast reach of an explosive block, from its centre
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum BlockType {
     Normal,
     Ice,           // 2 hits to destroy
@@ -22,15 +27,19 @@ pub enum BlockType {
     Undestroyable, // Cannot be destroyed
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum BonusType {
     ExtraBall,
     LongPaddle,
     GhostBall,
     Rocket,
+    // Nasty capsules (versus) - sent to the opponent
+    Shrink,  // Shrinks the paddle that catches it
+    SpeedUp, // Speeds up every ball in play
+    Heart,   // Rescued from the penguin - gives the life back
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -49,9 +58,7 @@ pub struct Paddle {
     pub width: i32,
     pub normal_width: i32,
     pub long_width: i32,
-    pub bonus_timer: u32,
-    pub ghost_timer: u32, // Timer for Ghost Ball mode
-    pub rocket_ammo: u32, // Ammo for Rocket bonus
+    pub effects: ActiveEffects, // Long paddle, ghost ball, rockets, shrink
     pub last_x: i32,
     pub vel_x: i32,
     pub spin_intensity: f32,
@@ -66,9 +73,7 @@ impl Paddle {
             width: normal_width,
             normal_width,
             long_width: normal_width + 40,
-            bonus_timer: 0,
-            ghost_timer: 0,
-            rocket_ammo: 0,
+            effects: ActiveEffects::default(),
             last_x: (WINDOW_WIDTH as i32 - normal_width) / 2,
             vel_x: 0,
             spin_intensity: 0.0,
@@ -87,23 +92,58 @@ impl Paddle {
         self.x = x.clamp(0, WINDOW_WIDTH as i32 - self.width);
     }
 
-    pub fn activate_long_bonus(&mut self) {
-        if self.width == self.normal_width {
-            let center = self.x + self.width / 2;
-            self.width = self.long_width;
-            self.x = center - self.width / 2;
-            // Clamp to screen
-            self.x = self.x.clamp(0, WINDOW_WIDTH as i32 - self.width);
+    /// Catch a power-up or penalty; its stack rule decides what a repeat catch does
+    pub fn add_effect(&mut self, kind: EffectKind) {
+        if self.effects.add(kind) {
+            self.on_effect_start(kind);
+        } else {
+            // Stacked long paddles grow wider
+            self.refresh_width();
         }
-        self.bonus_timer = 300; // 5 seconds at 60 FPS
     }
 
-    pub fn activate_ghost_bonus(&mut self) {
-        self.ghost_timer = 600; // 10 seconds at 60 FPS
+    pub fn has_effect(&self, kind: EffectKind) -> bool {
+        self.effects.is_active(kind)
     }
 
-    pub fn add_rockets(&mut self) {
-        self.rocket_ammo += 1; // Add 1 rocket
+    pub fn rocket_ammo(&self) -> u32 {
+        self.effects.stacks(EffectKind::Rockets)
+    }
+
+    /// Take one rocket off the paddle, if it has any
+    pub fn take_rocket(&mut self) -> bool {
+        self.effects.consume(EffectKind::Rockets)
+    }
+
+    fn on_effect_start(&mut self, kind: EffectKind) {
+        match kind {
+            EffectKind::LongPaddle | EffectKind::Shrink => self.refresh_width(),
+            EffectKind::GhostBall | EffectKind::Rockets => {}
+        }
+    }
+
+    fn on_effect_end(&mut self, kind: EffectKind) {
+        match kind {
+            EffectKind::LongPaddle | EffectKind::Shrink => self.refresh_width(),
+            EffectKind::GhostBall | EffectKind::Rockets => {}
+        }
+    }
+
+    /// Recompute width from active effects, keeping the paddle centered
+    fn refresh_width(&mut self) {
+        let long_stacks = self.effects.stacks(EffectKind::LongPaddle) as i32;
+        let mut width = self.normal_width + (self.long_width - self.normal_width) * long_stacks;
+        if self.has_effect(EffectKind::Shrink) {
+            width = width * 2 / 3;
+        }
+
+        if width != self.width {
+            let center = self.x + self.width / 2;
+            self.width = width;
+            self.x = center - self.width / 2;
+            // Clamp to screen
+            self.x = self.x.clamp(0, WINDOW_WIDTH as i32 - self.width);
+        }
     }
 
     pub fn update(&mut self) {
@@ -116,27 +156,22 @@ impl Paddle {
             self.spin_intensity = 0.0;
         }
 
-        if self.bonus_timer > 0 {
-            self.bonus_timer -= 1;
-            if self.bonus_timer == 0 {
-                let center = self.x + self.width / 2;
-                self.width = self.normal_width;
-                self.x = center - self.width / 2;
-                // Clamp to screen
-                self.x = self.x.clamp(0, WINDOW_WIDTH as i32 - self.width);
-            }
-        }
-
-        if self.ghost_timer > 0 {
-            self.ghost_timer -= 1;
+        for kind in self.effects.tick() {
+            self.on_effect_end(kind);
         }
     }
 
     pub fn rect(&self) -> Rect {
         Rect::new(self.x, self.y, self.width as u32, PADDLE_HEIGHT as u32)
     }
+
+    /// Defends the top edge (versus)
+    pub fn is_top(&self) -> bool {
+        self.y < WINDOW_HEIGHT as i32 / 2
+    }
 }
 
+#[derive(Clone)]
 pub struct Ball {
     pub x: f32,
     pub y: f32,
@@ -147,10 +182,25 @@ pub struct Ball {
     pub trail_positions: std::collections::VecDeque<(f32, f32)>, // Recent positions for trail effect
     pub attached_to_paddle: bool, // Ball starts attached, auto-launches after delay
     pub launch_timer: u32, // Frames to wait before auto-launch
+    pub aim: f32, // Launch angle from straight off the paddle, in radians (negative = left)
+    pub owner: usize, // Player whose paddle last touched the ball (co-op)
 }
 
+/// Steepest launch angle either side of straight off the paddle (60 degrees)
+pub const MAX_AIM: f32 = std::f32::consts::FRAC_PI_3;
+
 impl Ball {
     pub fn new(x: f32, y: f32) -> Self {
+        use rand::Rng;
+        let mut rng = rng::game_rng();
+
+        // Randomly choose initial aim: left-up, straight up or right-up
+        let aim = match rng.gen_range(0..3) {
+            0 => -std::f32::consts::FRAC_PI_4,
+            1 => 0.0,
+            _ => std::f32::consts::FRAC_PI_4,
+        };
+
         Ball {
             x,
             y,
@@ -161,39 +211,42 @@ impl Ball {
             trail_positions: std::collections::VecDeque::new(),
             attached_to_paddle: true, // Start attached
             launch_timer: 30, // Auto-launch after 0.5 seconds (30 frames at 60 FPS)
+            aim,
+            owner: 0,
+        }
+    }
+
+    /// Turn the aim, staying within the launch cone
+    pub fn set_aim(&mut self, aim: f32) {
+        self.aim = aim.clamp(-MAX_AIM, MAX_AIM);
+    }
+
+    /// Velocity the ball leaves an upward-facing paddle with at the current aim
+    pub fn launch_velocity(&self) -> (f32, f32) {
+        let vel_y = -4.0; // Always go up
+        let mut vel_x = -vel_y * self.aim.tan();
+        // Same minimum horizontal speed the physics enforces
+        if vel_x.abs() < 2.0 {
+            vel_x = if vel_x >= 0.0 { 2.0 } else { -2.0 };
         }
+        (vel_x, vel_y)
     }
     
     pub fn launch(&mut self) {
         if self.attached_to_paddle {
-            use rand::Rng;
-            let mut rng = rand::thread_rng();
-            
-            // Randomly choose initial direction: 0 = left-up, 1 = straight up, 2 = right-up
-            let direction = rng.gen_range(0..3);
-            self.vel_x = match direction {
-                0 => -4.0,  // Left-up
-                1 => 0.0,   // Straight up
-                _ => 4.0,   // Right-up
-            };
-            self.vel_y = -4.0; // Always go up
+            (self.vel_x, self.vel_y) = self.launch_velocity();
             self.attached_to_paddle = false;
         }
     }
 
-    pub fn update(&mut self, gravity_mode: bool) {
+    /// `top_goal`: the top edge is a goal (versus) instead of a wall
+    pub fn update(&mut self, modifiers: &Modifiers, top_goal: bool) {
         if !self.active {
             return;
         }
         
-        // If attached to paddle, count down to auto-launch
+        // Attached balls ride the paddle until launched
         if self.attached_to_paddle {
-            if self.launch_timer > 0 {
-                self.launch_timer -= 1;
-            } else {
-                // Auto-launch when timer expires
-                self.launch();
-            }
             return;
         }
         
@@ -219,27 +272,17 @@ impl Ball {
             self.trail_positions.clear();
         }
         
-        // Apply gravity mode physics - accelerate downward with terminal velocity
-    if gravity_mode && self.vel_y > 0.0 {
-        const TERMINAL_VELOCITY: f32 = 9.0; // Maximum downward speed (realistic physics)
-        if self.vel_y < TERMINAL_VELOCITY {
-            self.vel_y += 0.3; // Gravity acceleration
-            // Cap at terminal velocity
-            if self.vel_y > TERMINAL_VELOCITY {
-                self.vel_y = TERMINAL_VELOCITY;
-            }
-        }
-    }
+        // Modifier physics (gravity, speed floor, ...)
+        modifiers.ball_physics(self);
     
-    // Apply spin (Magnus effect approximation) - DISABLED in gravity mode
-    if !gravity_mode {
-        self.vel_x += self.spin * 0.05;
-        // Decay spin
-        self.spin *= 0.98;
-    } else {
-        // Gravity mode: zero out spin
-        self.spin = 0.0;
-    }    
+        // Apply spin (Magnus effect approximation) - unless a modifier disables it
+        if modifiers.allows_spin() {
+            self.vel_x += self.spin * 0.05;
+            // Decay spin
+            self.spin *= 0.98;
+        } else {
+            self.spin = 0.0;
+        }
         self.x += self.vel_x;
         self.y += self.vel_y;
 
@@ -252,7 +295,12 @@ impl Ball {
             self.vel_x = -self.vel_x.abs(); // Force negative
         }
         
-        if self.y <= 0.0 {
+        if top_goal {
+            // Top boundary - deactivate ball once it is fully past the edge
+            if self.y <= -(BALL_SIZE as f32) {
+                self.active = false;
+            }
+        } else if self.y <= 0.0 {
             self.y = 0.0;
             self.vel_y = self.vel_y.abs(); // Force positive
         }
@@ -287,6 +335,10 @@ pub struct Block {
     pub block_type: BlockType,
     pub health: u8,
     pub max_health: u8,
+    pub shake: u32, // Frames left shaking after a shockwave
+    pub drop: Option<BonusType>, // Capsule this block always drops
+    pub width: i32,
+    pub height: i32,
 }
 
 impl Block {
@@ -305,19 +357,47 @@ impl Block {
             block_type,
             health,
             max_health,
+            shake: 0,
+            drop: None,
+            width: BLOCK_WIDTH,
+            height: BLOCK_HEIGHT,
         }
     }
 
+    /// Same block with a level's own brick size
+    pub fn with_size(mut self, width: i32, height: i32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
     pub fn rect(&self) -> Rect {
-        Rect::new(self.x, self.y, BLOCK_WIDTH as u32, BLOCK_HEIGHT as u32)
+        Rect::new(self.x, self.y, self.width as u32, self.height as u32)
+    }
+
+    /// Draw offset while shaking, fading out with the timer
+    pub fn shake_offset(&self) -> (i32, i32) {
+        if self.shake == 0 {
+            return (0, 0);
+        }
+        let amplitude = (self.shake as f32 / BLOCK_SHAKE_FRAMES as f32) * 3.0;
+        let phase = self.shake as f32 * 1.7;
+        ((phase.sin() * amplitude) as i32, ((phase * 1.3).cos() * amplitude) as i32)
     }
 }
 
+/// How long a block shakes after a shockwave passes over it
+pub const BLOCK_SHAKE_FRAMES: u32 = 18;
+
 pub struct Bonus {
     pub x: f32,
     pub y: f32,
+    pub vel_y: f32, // Falls down; negative rises to the top paddle (versus)
+    pub push_x: f32, // Knockback from shockwaves, fades out
+    pub push_y: f32,
     pub bonus_type: BonusType,
     pub active: bool,
+    pub rescued: bool, // A lost heart knocked back out of the penguin
 }
 
 impl Bonus {
@@ -325,14 +405,29 @@ impl Bonus {
         Bonus {
             x,
             y,
+            vel_y: 2.0,
+            push_x: 0.0,
+            push_y: 0.0,
             bonus_type,
             active: true,
+            rescued: false,
         }
     }
 
+    /// The heart the penguin stole, knocked loose again
+    pub fn rescued_heart(x: f32, y: f32) -> Self {
+        Bonus { rescued: true, ..Bonus::new(x, y, BonusType::Heart) }
+    }
+
     pub fn update(&mut self) {
-        self.y += 2.0;
-        if self.y > WINDOW_HEIGHT as f32 {
+        self.x += self.push_x;
+        self.y += self.vel_y + self.push_y;
+        self.push_x *= 0.9;
+        self.push_y *= 0.9;
+
+        // Knocked capsules stay on screen horizontally
+        self.x = self.x.clamp(0.0, (WINDOW_WIDTH - 40) as f32);
+        if self.y > WINDOW_HEIGHT as f32 || self.y < -40.0 {
             self.active = false;
         }
     }
@@ -345,7 +440,9 @@ impl Bonus {
 pub struct Rocket {
     pub x: f32,
     pub y: f32,
+    pub vel_y: f32, // Flies up; positive flies down from the top paddle (versus)
     pub active: bool,
+    pub owner: usize, // Player who fired it (co-op)
 }
 
 impl Rocket {
@@ -353,13 +450,15 @@ impl Rocket {
         Rocket {
             x,
             y,
+            vel_y: -8.0, // Move up fast
             active: true,
+            owner: 0,
         }
     }
 
     pub fn update(&mut self) {
-        self.y -= 8.0; // Move up fast
-        if self.y < 0.0 {
+        self.y += self.vel_y;
+        if self.y < 0.0 || self.y > WINDOW_HEIGHT as f32 {
             self.active = false;
         }
     }
@@ -386,7 +485,7 @@ pub struct Particle {
 impl Particle {
     pub fn new(x: f32, y: f32, vel_x: f32, vel_y: f32, color: Color) -> Self {
         use rand::Rng;
-        let mut rng = rand::thread_rng();
+        let mut rng = rng::game_rng();
         
         Particle {
             x,
@@ -420,12 +519,112 @@ impl Particle {
     }
 }
 
+/// Expanding ring from an explosion. Whatever the front sweeps over gets pushed outward.
+pub struct Shockwave {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    pub prev_radius: f32, // Radius last frame - the front covers prev_radius..radius
+    pub max_radius: f32,
+}
+
+impl Shockwave {
+    const SPEED: f32 = 7.0;   // Ring growth per frame
+    const STRENGTH: f32 = 4.0; // Push at the center, falling off to zero at max_radius
+
+    pub fn new(x: f32, y: f32, max_radius: f32) -> Self {
+        Shockwave {
+            x,
+            y,
+            radius: 0.0,
+            prev_radius: 0.0,
+            max_radius,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.prev_radius = self.radius;
+        self.radius = (self.radius + Self::SPEED).min(self.max_radius);
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.prev_radius < self.max_radius
+    }
+
+    /// Whether the front passed over this point during the last update
+    pub fn sweeps(&self, x: f32, y: f32) -> bool {
+        let dist = ((x - self.x).powi(2) + (y - self.y).powi(2)).sqrt();
+        dist > self.prev_radius && dist <= self.radius
+    }
+
+    /// Outward push for something the front just swept over
+    pub fn push_at(&self, x: f32, y: f32) -> Option<(f32, f32)> {
+        if !self.sweeps(x, y) {
+            return None;
+        }
+        let (dx, dy) = (x - self.x, y - self.y);
+        let dist = (dx * dx + dy * dy).sqrt().max(1.0);
+        let strength = Self::STRENGTH * (1.0 - self.radius / self.max_radius).max(0.2);
+        Some((dx / dist * strength, dy / dist * strength))
+    }
+
+    pub fn alpha(&self) -> u8 {
+        ((1.0 - self.radius / self.max_radius) * 220.0) as u8
+    }
+}
+
+/// Floating "+N xM" score text shown where a block was destroyed
+pub struct ScorePopup {
+    pub x: f32,
+    pub y: f32,
+    pub points: u32,
+    pub multiplier: u32,
+    pub lifetime: u32,
+    pub max_lifetime: u32,
+}
+
+impl ScorePopup {
+    pub fn new(x: f32, y: f32, points: u32, multiplier: u32) -> Self {
+        ScorePopup {
+            x,
+            y,
+            points,
+            multiplier,
+            lifetime: 0,
+            max_lifetime: 50,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.y -= 0.8; // Drift upward
+        self.lifetime += 1;
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.lifetime < self.max_lifetime
+    }
+
+    pub fn alpha(&self) -> u8 {
+        let progress = self.lifetime as f32 / self.max_lifetime as f32;
+        ((1.0 - progress) * 255.0) as u8
+    }
+
+    pub fn text(&self) -> String {
+        if self.multiplier > 1 {
+            format!("+{} x{}", self.points, self.multiplier)
+        } else {
+            format!("+{}", self.points)
+        }
+    }
+}
+
 /// Penguin animation states for heart theft
 #[derive(Clone, Copy, PartialEq)]
 pub enum PenguinState {
     WalkingIn,     // Walking from right edge to heart
     Grabbing,      // Grabbing animation at heart
     RunningAway,   // Running back off screen
+    Hit,           // Knocked by a ball or rocket - drops the heart
     Done,          // Animation complete
 }
 
@@ -437,10 +636,12 @@ pub struct Penguin {
     pub target_y: f32,  // Heart position Y
     pub state: PenguinState,
     pub frame_count: u32,
+    pub catchable: bool, // Heart rescue: escapes across the playfield and can be hit
+    pub has_heart: bool,
 }
 
 impl Penguin {
-    pub fn new(target_x: f32, target_y: f32) -> Self {
+    pub fn new(target_x: f32, target_y: f32, catchable: bool) -> Self {
         Penguin {
             x: WINDOW_WIDTH as f32 - 50.0, // Start bottom-right
             y: WINDOW_HEIGHT as f32 - 100.0, // Near bottom
@@ -448,6 +649,8 @@ impl Penguin {
             target_y,
             state: PenguinState::WalkingIn,
             frame_count: 0,
+            catchable,
+            has_heart: false,
         }
     }
 
@@ -478,18 +681,34 @@ impl Penguin {
                 if self.frame_count >= 30 {
                     self.state = PenguinState::RunningAway;
                     self.frame_count = 0;
+                    self.has_heart = true;
                 }
             }
             PenguinState::RunningAway => {
-                // Fly down and right, even faster!
-                self.x += 7.0;
-                self.y += 5.0;
+                if self.catchable && self.has_heart {
+                    // Slow escape left over the blocks - a chance to hit it
+                    self.x -= 3.5;
+                    self.y += 0.6;
+                } else {
+                    // Fly down and right, even faster!
+                    self.x += 7.0;
+                    self.y += 5.0;
+                }
                 
                 // Off screen?
-                if self.x > WINDOW_WIDTH as f32 + 100.0 || self.y > WINDOW_HEIGHT as f32 + 100.0 {
+                if self.x > WINDOW_WIDTH as f32 + 100.0 || self.x < -100.0 || self.y > WINDOW_HEIGHT as f32 + 100.0 {
                     self.state = PenguinState::Done;
                 }
             }
+            PenguinState::Hit => {
+                // Tumble back for half a second, then flee empty-handed
+                self.x += 2.0;
+                self.y -= 1.0;
+                if self.frame_count >= 30 {
+                    self.state = PenguinState::RunningAway;
+                    self.frame_count = 0;
+                }
+            }
             PenguinState::Done => {
                 // Animation complete, do nothing
             }
@@ -499,9 +718,112 @@ impl Penguin {
     pub fn is_done(&self) -> bool {
         self.state == PenguinState::Done
     }
+
+    /// Body hitbox (matches the drawing)
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x as i32, self.y as i32, 25, 40)
+    }
+
+    /// Can be knocked: running off with the heart, and heart rescue is on
+    pub fn is_catchable(&self) -> bool {
+        self.catchable && self.has_heart && self.state == PenguinState::RunningAway
+    }
+
+    /// Knocked by a ball or rocket - lets go of the heart
+    pub fn hit(&mut self) {
+        self.state = PenguinState::Hit;
+        self.frame_count = 0;
+        self.has_heart = false;
+    }
 }
 
 
+/// Block grid of a level: cell count and brick size, centred across the window
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GridLayout {
+    pub rows: usize,
+    pub cols: usize,
+    pub block_width: i32,
+    pub block_height: i32,
+}
+
+impl Default for GridLayout {
+    fn default() -> Self {
+        GridLayout {
+            rows: BLOCK_ROWS,
+            cols: BLOCK_COLS,
+            block_width: BLOCK_WIDTH,
+            block_height: BLOCK_HEIGHT,
+        }
+    }
+}
+
+impl GridLayout {
+    /// Layouts offered by the level editor: standard, small bricks, large bricks
+    pub const PRESETS: [GridLayout; 3] = [
+        GridLayout { rows: BLOCK_ROWS, cols: BLOCK_COLS, block_width: BLOCK_WIDTH, block_height: BLOCK_HEIGHT },
+        GridLayout { rows: 14, cols: 26, block_width: 46, block_height: 15 },
+        GridLayout { rows: 6, cols: 12, block_width: 90, block_height: 32 },
+    ];
+
+    /// Largest field: the window width, and down to well above the paddle
+    const MAX_FIELD_HEIGHT: i32 = 320;
+
+    /// Why a layout cannot be played, if it cannot
+    pub fn check(&self) -> Result<(), String> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err("grid needs at least one row and one column".to_string());
+        }
+        if self.block_width < 20 || self.block_height < 12 {
+            return Err(format!("blocks must be at least 20x12 (got {}x{})", self.block_width, self.block_height));
+        }
+        if self.width() > WINDOW_WIDTH as i32 || self.height() > Self::MAX_FIELD_HEIGHT {
+            return Err(format!(
+                "{}x{} grid of {}x{} blocks does not fit the playfield",
+                self.cols, self.rows, self.block_width, self.block_height
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn width(&self) -> i32 {
+        self.cols as i32 * self.block_width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.rows as i32 * self.block_height
+    }
+
+    /// Left edge of the centred grid
+    pub fn offset_x(&self) -> i32 {
+        (WINDOW_WIDTH as i32 - self.width()) / 2
+    }
+
+    /// Top-left corner of a cell
+    pub fn cell_position(&self, row: usize, col: usize) -> (i32, i32) {
+        (
+            self.offset_x() + col as i32 * self.block_width,
+            BLOCK_OFFSET_Y + row as i32 * self.block_height,
+        )
+    }
+
+    /// Cell under a screen point
+    pub fn cell_at(&self, x: i32, y: i32) -> Option<(usize, usize)> {
+        let offset_x = self.offset_x();
+        if x < offset_x || x >= offset_x + self.width() || y < BLOCK_OFFSET_Y || y >= BLOCK_OFFSET_Y + self.height() {
+            return None;
+        }
+        let col = ((x - offset_x) / self.block_width) as usize;
+        let row = ((y - BLOCK_OFFSET_Y) / self.block_height) as usize;
+        Some((row, col))
+    }
+
+    pub fn block(&self, row: usize, col: usize, color: Color, block_type: BlockType) -> Block {
+        let (x, y) = self.cell_position(row, col);
+        Block::new(x, y, color, block_type).with_size(self.block_width, self.block_height)
+    }
+}
+
 // Block colors (rainbow pattern)
 pub const BLOCK_COLORS: [Color; 6] = [
     Color::new(255, 0, 0),     // Red
@@ -512,7 +834,175 @@ pub const BLOCK_COLORS: [Color; 6] = [
     Color::new(138, 43, 226),  // Violet
 ];
 
+/// Block field for a level: campaign levels come from campaign.json,
+/// infinite levels past the campaign are generated
 pub fn create_blocks(level: usize) -> Vec<Block> {
+    // Boss levels have no block field - the boss takes up the whole area
+    if crate::boss::is_boss_level(level) {
+        return Vec::new();
+    }
+
+    match crate::campaign::campaign().level(level) {
+        Some(def) => def.blocks.create(),
+        None => generate_blocks(level),
+    }
+}
+
+/// Built-in generators: 1-9 are the handcrafted layouts, 10+ random patterns seeded by the number.
+/// Infinite levels also draw from the player's own patterns/ directory.
+pub fn generate_blocks(level: usize) -> Vec<Block> {
+    use once_cell::sync::Lazy;
+
+    // Lazy-load custom patterns from patterns/ directory
+    static CUSTOM_PATTERNS: Lazy<Vec<crate::editor::PatternData>> = Lazy::new(|| {
+        crate::editor::load_all_patterns("patterns")
+    });
+
+    // Only infinite levels need them, so the directory is not read before then
+    let custom_patterns: &[crate::editor::PatternData] = if level >= 10 { &CUSTOM_PATTERNS } else { &[] };
+    generate_blocks_from(level, SpecialOdds::for_level(level), custom_patterns)
+}
+
+/// Random level from the 12 built-in generators only, with the special block
+/// odds given separately from the layout number. Daily levels use this, so the
+/// same day gives the same level whatever patterns a player has installed.
+pub fn generate_builtin_blocks(level: usize, odds: SpecialOdds) -> Vec<Block> {
+    generate_blocks_from(level, odds, &[])
+}
+
+/// Chance of each special block type in a generated level, per block
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SpecialOdds {
+    pub ice: f32,
+    pub explosive: f32,
+    pub undestroyable: f32,
+}
+
+impl SpecialOdds {
+    /// Infinite mode difficulty curve: ice and explosives from level 10,
+    /// undestroyable blocks from level 15, all ramping up until level 50
+    pub fn for_level(level: usize) -> Self {
+        if level < 10 {
+            return SpecialOdds::default();
+        }
+
+        let ramp = ((level - 10) as f32 / 40.0).min(1.0);
+        SpecialOdds {
+            ice: 0.05 + 0.15 * ramp,
+            explosive: 0.02 + 0.06 * ramp,
+            undestroyable: if level < 15 { 0.0 } else { 0.02 + 0.08 * ramp },
+        }
+    }
+
+    /// Block type for a 0.0-1.0 roll
+    fn pick(&self, roll: f32) -> BlockType {
+        if roll < self.undestroyable {
+            BlockType::Undestroyable
+        } else if roll < self.undestroyable + self.explosive {
+            BlockType::Explosive
+        } else if roll < self.undestroyable + self.explosive + self.ice {
+            BlockType::Ice
+        } else {
+            BlockType::Normal
+        }
+    }
+}
+
+/// How a procedural pattern spreads its special blocks
+#[derive(Clone, Copy, PartialEq)]
+enum SpecialPlacement {
+    Scatter, // Each block rolls on its own
+    Mirror,  // Right half copies the left half (symmetry)
+    Bands,   // One roll per row, following the stripes (diagonals, checkerboard, honeycomb)
+    Tiles,   // Every invader gets the same specials
+}
+
+impl SpecialPlacement {
+    fn for_pattern(pattern_type: usize) -> Self {
+        match pattern_type {
+            2 | 4 | 6 => SpecialPlacement::Bands,
+            7 => SpecialPlacement::Mirror,
+            10 => SpecialPlacement::Tiles,
+            _ => SpecialPlacement::Scatter,
+        }
+    }
+
+    /// Cell whose roll decides this cell's type
+    fn source(self, row: usize, col: usize) -> (usize, usize) {
+        match self {
+            SpecialPlacement::Scatter => (row, col),
+            SpecialPlacement::Mirror => (row, col.min(BLOCK_COLS - 1 - col)),
+            SpecialPlacement::Bands => (row, 0),
+            SpecialPlacement::Tiles => (row % 5, col % 6),
+        }
+    }
+}
+
+/// Seed of an infinite level's pattern choice; retries shift it by attempt
+fn infinite_seed(level: usize, attempt: u32) -> u64 {
+    // Use multiple entropy sources for better randomization
+    (level as u64).wrapping_mul(54321)
+        .wrapping_add((level as u64 % 7).wrapping_mul(11111))
+        .wrapping_add((level as u64 / 5).wrapping_mul(99999))
+        .wrapping_add((attempt as u64).wrapping_mul(77777))
+}
+
+/// Turn some blocks of a generated level into specials. Undestroyable blocks
+/// are placed one roll at a time and dropped again if the validator finds
+/// they seal a destroyable block in.
+fn add_special_blocks(blocks: &mut [Block], level: usize, attempt: u32, placement: SpecialPlacement, odds: SpecialOdds) {
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+    use std::collections::BTreeMap;
+    use crate::editor::{PatternCell, PatternData};
+
+    let layout = GridLayout::default();
+    let mut pattern = PatternData::with_layout(String::new(), layout);
+    // Undestroyable candidates (block indices), by the cell that rolled them
+    let mut walls: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+
+    for (index, block) in blocks.iter_mut().enumerate() {
+        let Some((row, col)) = layout.cell_at(block.x, block.y) else {
+            continue;
+        };
+        let (source_row, source_col) = placement.source(row, col);
+        let seed = (level as u64 * 1000 + source_row as u64 * 100 + source_col as u64 + attempt as u64) ^ 0x5EC1A1;
+        match odds.pick(StdRng::seed_from_u64(seed).gen()) {
+            BlockType::Undestroyable => walls.entry((source_row, source_col)).or_default().push(index),
+            BlockType::Normal => {}
+            block_type => *block = Block::new(block.x, block.y, block.color, block_type),
+        }
+        pattern.grid[row][col] = Some(PatternCell { color: block.color, block_type: block.block_type, hits: None, drop: None });
+    }
+
+    for group in walls.values() {
+        let set = |pattern: &mut PatternData, blocks: &[Block], block_type: BlockType| {
+            for &index in group {
+                if let Some((row, col)) = layout.cell_at(blocks[index].x, blocks[index].y) {
+                    if let Some(cell) = pattern.grid[row][col].as_mut() {
+                        cell.block_type = block_type;
+                    }
+                }
+            }
+        };
+
+        set(&mut pattern, blocks, BlockType::Undestroyable);
+        if crate::validator::sealed_blocks(&pattern).is_empty() {
+            for &index in group {
+                let block = &blocks[index];
+                blocks[index] = Block::new(block.x, block.y, block.color, BlockType::Undestroyable);
+            }
+        } else {
+            set(&mut pattern, blocks, BlockType::Normal);
+        }
+    }
+}
+
+/// Shared generator; infinite levels pick from the 12 procedural patterns plus `custom_patterns`
+fn generate_blocks_from(level: usize, odds: SpecialOdds, custom_patterns: &[crate::editor::PatternData]) -> Vec<Block> {
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+
     // For infinite mode (level 10+), ensure we have a minimum number of blocks
     // by retrying with different seeds if needed
     const MIN_BLOCKS: usize = 20;
@@ -530,28 +1020,6 @@ pub fn create_blocks(level: usize) -> Vec<Block> {
                 let y = BLOCK_OFFSET_Y + row as i32 * BLOCK_HEIGHT;
                 let color = BLOCK_COLORS[row % BLOCK_COLORS.len()];
                 
-                // Determine block type based on level and randomness
-                // Only introduce special blocks for infinite mode (level 10+)
-                let block_type = if level >= 10 {
-                    /* 
-                    // Temporarily disabled special block generation for infinite mode
-                    // as per user request to "bring back previous versions" of patterns
-                    let mut rng = rand::thread_rng();
-                    let chance = rng.gen_range(0..100);
-                    
-                    // Infinite mode: Chance for special blocks
-                    match chance {
-                        0..=4 => BlockType::Explosive,     // 5%
-                        5..=14 => BlockType::Ice,          // 10%
-                        15..=19 => BlockType::Undestroyable, // 5%
-                        _ => BlockType::Normal,
-                    }
-                    */
-                    BlockType::Normal
-                } else {
-                    BlockType::Normal
-                };
-                
                 let should_add = if level <= 9 {
                     // Predefined patterns for levels 1-9
                     match level {
@@ -599,31 +1067,16 @@ pub fn create_blocks(level: usize) -> Vec<Block> {
                     }
                 } else {
                     // Random patterns for levels 10+ (seeded by level number)
-                    use rand::{Rng, SeedableRng};
-                    use rand::rngs::StdRng;
-                    use once_cell::sync::Lazy;
-                    
-                    // Lazy-load custom patterns from patterns/ directory
-                    static CUSTOM_PATTERNS: Lazy<Vec<crate::editor::PatternData>> = Lazy::new(|| {
-                        crate::editor::load_all_patterns("patterns")
-                    });
-                    
-                    // Use multiple entropy sources for better randomization
-                    // Add attempt number to seed for retries
-                    let seed = (level as u64).wrapping_mul(54321)
-                        .wrapping_add((level as u64 % 7).wrapping_mul(11111))
-                        .wrapping_add((level as u64 / 5).wrapping_mul(99999))
-                        .wrapping_add((attempt as u64).wrapping_mul(77777));
-                    let mut rng = StdRng::seed_from_u64(seed);
+                    let mut rng = StdRng::seed_from_u64(infinite_seed(level, attempt));
                     
                     // Total pattern count: 12 procedural + N custom
-                    let total_patterns = 12 + CUSTOM_PATTERNS.len();
+                    let total_patterns = 12 + custom_patterns.len();
                     let pattern_type = rng.gen_range(0..total_patterns);
                     
                     // If custom pattern selected, use it directly
-                    if pattern_type >= 12 && !CUSTOM_PATTERNS.is_empty() {
+                    if pattern_type >= 12 && !custom_patterns.is_empty() {
                         let custom_idx = pattern_type - 12;
-                        return crate::editor::create_blocks_from_pattern(&CUSTOM_PATTERNS[custom_idx]);
+                        return crate::editor::create_blocks_from_pattern(&custom_patterns[custom_idx]);
                     }
                     
                     // Otherwise use procedural pattern (0-11)
@@ -734,7 +1187,7 @@ pub fn create_blocks(level: usize) -> Vec<Block> {
                 };
 
                 if should_add {
-                    blocks.push(Block::new(x, y, color, block_type));
+                    blocks.push(Block::new(x, y, color, BlockType::Normal));
                 }
             }
         }
@@ -742,6 +1195,11 @@ pub fn create_blocks(level: usize) -> Vec<Block> {
         // For levels 1-9, always return the pattern
         // For levels 10+, ensure minimum block count
         if level <= 9 || blocks.len() >= MIN_BLOCKS || attempt >= MAX_RETRIES {
+            if level >= 10 {
+                // Same draw as the pattern choice above (custom patterns returned already)
+                let pattern_type = StdRng::seed_from_u64(infinite_seed(level, attempt)).gen_range(0..12 + custom_patterns.len());
+                add_special_blocks(&mut blocks, level, attempt, SpecialPlacement::for_pattern(pattern_type), odds);
+            }
             return blocks;
         }
         
@@ -750,6 +1208,34 @@ pub fn create_blocks(level: usize) -> Vec<Block> {
     }
 }
 
+/// Versus playfield: a wall of blocks across the middle, between the two paddles
+pub fn create_versus_blocks() -> Vec<Block> {
+    const WALL_ROWS: usize = 6;
+    const WALL_COLS: usize = 16;
+
+    let mut blocks = Vec::new();
+    let offset_x = (WINDOW_WIDTH as i32 - WALL_COLS as i32 * BLOCK_WIDTH) / 2;
+    let offset_y = (WINDOW_HEIGHT as i32 - WALL_ROWS as i32 * BLOCK_HEIGHT) / 2;
+
+    for row in 0..WALL_ROWS {
+        for col in 0..WALL_COLS {
+            let x = offset_x + col as i32 * BLOCK_WIDTH;
+            let y = offset_y + row as i32 * BLOCK_HEIGHT;
+            let color = BLOCK_COLORS[row % BLOCK_COLORS.len()];
+
+            // Ice core in the two middle rows makes the wall harder to punch through
+            let block_type = if row == WALL_ROWS / 2 - 1 || row == WALL_ROWS / 2 {
+                BlockType::Ice
+            } else {
+                BlockType::Normal
+            };
+            blocks.push(Block::new(x, y, color, block_type));
+        }
+    }
+
+    blocks
+}
+
 pub fn check_collision(rect1: Rect, rect2: Rect) -> bool {
     rect1.has_intersection(rect2)
 }
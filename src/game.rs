@@ -1,5 +1,16 @@
 use crate::entities::*;
+use crate::boss::*;
+use crate::stats::*;
+use crate::time_attack::*;
+use crate::daily::*;
+use crate::bonus_stage::*;
+use crate::modifiers::*;
+use crate::campaign::*;
+use crate::editor::{create_blocks_from_pattern, PatternData, PatternMeta};
+use crate::effects::EffectKind;
+use crate::{packs, rng};
 use rand::Rng;
+use sdl2::rect::Rect;
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum GameState {
@@ -10,11 +21,43 @@ pub enum GameState {
     Victory,
     LevelTransition,
     LevelEditor,
+    PlayerReady, // Hot-seat interstitial before the next player's turn
 }
 
+/// How a run is played and won
+#[derive(PartialEq, Clone, Copy)]
+pub enum GameMode {
+    Campaign,   // Campaign levels, then infinite mode
+    TimeAttack, // Fixed set of levels against the clock
+    HotSeat,    // Two players alternate turns, passing on each lost life
+    CoOp,       // Two paddles on one playfield, shared lives
+    Versus,     // Paddles top and bottom, first to VERSUS_GOALS wins
+    Daily,      // DAILY_LEVELS levels picked by the date, one counted run a day
+}
+
+/// Goals needed to win a versus match
+pub const VERSUS_GOALS: u32 = 5;
+
+/// Aim turn per frame while a key is held (about 2.3 degrees)
+const AIM_SPEED: f32 = 0.04;
+
+/// Aiming holds off auto-launch for at least this many frames
+const AIM_HOLD_FRAMES: u32 = 45;
+
+/// Launch preview length: 4px steps in total, and after the first bounce
+const AIM_PREVIEW_STEPS: u32 = 200;
+const AIM_PREVIEW_AFTER_BOUNCE: u32 = 40;
+
+/// How far a shockwave travels past the blast that caused it
+const SHOCKWAVE_REACH: f32 = 100.0;
+
+/// Slowest vertical speed a shockwave can leave the ball with (px per frame)
+const SHOCKWAVE_MIN_VEL_Y: f32 = 2.0;
+
 pub struct Game {
     pub state: GameState,
     pub paddle: Paddle,
+    pub paddle2: Option<Paddle>, // Player 2 paddle (co-op, or the top paddle in versus)
     pub balls: Vec<Ball>,
     pub blocks: Vec<Block>,
     pub bonuses: Vec<Bonus>,
@@ -30,10 +73,34 @@ pub struct Game {
     pub lost_life_this_level: bool, // Track if player lost a life this level
     pub max_speed: f32, // Maximum ball speed ever reached (px/s)
     pub max_speed_record_frame: u64, // Frame when new record was set (for effects)
-    pub portal_active: bool, // Portal activated at 3600 px/s
+    pub portal_active: bool, // Portal activated at the threshold speed
     pub portal_completion_timer: u64, // Frames since all blocks consumed (for animation delay)
-    pub gravity_mode: bool, // Gravity mode enabled (heavier physics, no spin)
+    pub modifiers: Modifiers, // Gameplay modifiers for this run, plus the level's own
+    run_modifiers: Vec<ModifierKind>, // Modifiers picked for the run
+    pub level_meta: PatternMeta, // Title, par time and music from the level file
     pub is_test_mode: bool, // Whether we are in editor test mode
+    pub boss: Option<Boss>, // Boss encounter (boss levels only)
+    pub boss_projectiles: Vec<BossProjectile>,
+    pub combo: u32, // Blocks destroyed since the last paddle touch
+    pub best_combo: u32, // Longest combo this level
+    pub score_popups: Vec<ScorePopup>,
+    pub shockwaves: Vec<Shockwave>, // Expanding rings from explosions
+    pub stats: LevelStats, // Collected during the current level
+    pub results: LevelResults, // Bonus breakdown of the last finished level
+    pub mode: GameMode,
+    pub time_attack: Option<TimeAttack>, // Timer and splits (time attack only)
+    pub daily: Option<DailyChallenge>, // Today's challenge (daily only)
+    pub player: usize, // 0 = Player 1, 1 = Player 2 (hot-seat)
+    pub other_player: Option<Box<Game>>, // Parked game of the waiting player (hot-seat)
+    pub turn_over: bool, // Life lost - pass the turn after this frame
+    pub player_scores: [u32; 2], // Points earned by each paddle (co-op)
+    pub goals: [u32; 2], // Balls each player got past the opponent (versus)
+    last_conceded: usize, // Player who let the last ball through - serves next (versus)
+    pub auto_launch: bool, // Resting balls launch by themselves after a short delay (setting)
+    pub heart_rescue: bool, // The penguin can be knocked to drop a stolen heart (setting)
+    pub portal: PortalConfig, // Portal threshold, rewards and bonus stage warp (setting)
+    pub bonus_stage: Option<BonusStage>, // Playing a bonus stage between levels
+    warp_pending: bool, // The portal closed the last level - warp before the next one
 }
 
 #[derive(Clone, Copy)]
@@ -58,9 +125,10 @@ impl Game {
             paddle.y as f32 - BALL_SIZE as f32,
         );
         
-        Game {
+        let mut game = Game {
             state: GameState::SplashScreen,
             paddle,
+            paddle2: None,
             balls: vec![initial_ball],
             blocks: create_blocks(level),
             bonuses: Vec::new(),
@@ -78,71 +146,544 @@ impl Game {
             max_speed_record_frame: 0,
             portal_active: false,
             portal_completion_timer: 0,
-            gravity_mode: false,
+            modifiers: Modifiers::default(),
+            run_modifiers: Vec::new(),
+            level_meta: level_meta(level),
             is_test_mode: false,
+            boss: if is_boss_level(level) { Some(Boss::new(level)) } else { None },
+            boss_projectiles: Vec::new(),
+            combo: 0,
+            best_combo: 0,
+            score_popups: Vec::new(),
+            shockwaves: Vec::new(),
+            stats: LevelStats::default(),
+            results: LevelResults::default(),
+            mode: GameMode::Campaign,
+            auto_launch: true,
+            heart_rescue: false,
+            portal: PortalConfig::default(),
+            bonus_stage: None,
+            warp_pending: false,
+            time_attack: None,
+            daily: None,
+            player: 0,
+            other_player: None,
+            turn_over: false,
+            player_scores: [0; 2],
+            goals: [0; 2],
+            last_conceded: 0,
+        };
+        // Picks up the level's own modifiers
+        game.set_modifiers(&[]);
+        game
+    }
+
+    /// Fresh game from level 1 in the given mode
+    pub fn with_mode(mode: GameMode, modifiers: &[ModifierKind]) -> Self {
+        let mut game = Game::new();
+        game.mode = mode;
+        if mode == GameMode::Daily {
+            // Counted and reseeded by start_run, once the run is really played
+            game.daily = Some(DailyChallenge::today());
+            game.load_level();
+        }
+        // The daily challenge brings its own modifiers
+        let modifiers = game.daily.as_ref().map_or(modifiers.to_vec(), |daily| daily.modifiers.clone());
+        game.set_modifiers(&modifiers);
+        if mode == GameMode::TimeAttack {
+            // Bests are kept apart for each pack and modifier selection
+            game.time_attack = Some(TimeAttack::new(&packs::current().id, &modifiers));
+        }
+        if mode == GameMode::HotSeat {
+            let mut player2 = Game::new();
+            player2.mode = mode;
+            player2.player = 1;
+            player2.set_modifiers(&modifiers);
+            game.other_player = Some(Box::new(player2));
+        }
+        if mode == GameMode::Versus {
+            game.blocks = create_versus_blocks();
+            game.level_meta = PatternMeta::default();
+        }
+        game
+    }
+
+    /// Play a level file in place of the campaign level (the campaign carries on after it)
+    pub fn with_pattern(level: usize, pattern: &PatternData) -> Self {
+        let mut game = Game::new_level(level);
+        game.blocks = create_blocks_from_pattern(pattern);
+        game.boss = None;
+        game.level_meta = pattern.meta.clone();
+        game
+    }
+
+    /// Restart the current mode with the given modifier selection
+    pub fn reset(&mut self, modifiers: &[ModifierKind]) {
+        *self = Game::with_mode(self.mode, modifiers);
+        self.start_run();
+    }
+
+    /// The player starts playing this game (not just a menu rebuild). A daily
+    /// run takes up the day's attempt here and reseeds, so capsules and the
+    /// rest of the run's randomness follow the date too.
+    pub fn start_run(&mut self) {
+        if let Some(ref mut daily) = self.daily {
+            daily.start();
+            rng::reseed(daily.seed);
+            // The resting ball rolled its aim before the reseed, roll it again
+            self.reset_paddle();
+        }
+    }
+
+    /// Switch modifiers - only meant for a run that has not started yet
+    pub fn set_modifiers(&mut self, modifiers: &[ModifierKind]) {
+        self.run_modifiers = modifiers.to_vec();
+        self.apply_level_modifiers();
+        self.lives = self.modifiers.max_lives();
+        self.reset_paddle();
+    }
+
+    /// Combine the run's modifiers with the ones the campaign sets for this level
+    fn apply_level_modifiers(&mut self) {
+        let mut kinds = self.run_modifiers.clone();
+        if self.mode != GameMode::Versus {
+            if let Some(def) = self.level_def() {
+                for kind in &def.modifiers {
+                    if !kinds.contains(kind) {
+                        kinds.push(*kind);
+                    }
+                }
+            }
+        }
+        self.modifiers = Modifiers::from_kinds(&kinds);
+        self.lives = self.lives.min(self.modifiers.max_lives());
+    }
+
+    /// New paddle(s) with the ball resting on Player 1's
+    fn reset_paddle(&mut self) {
+        self.paddle = Paddle::new();
+        self.modifiers.setup_paddle(&mut self.paddle);
+
+        if self.mode == GameMode::CoOp {
+            let mut paddle2 = Paddle::new();
+            self.modifiers.setup_paddle(&mut paddle2);
+            // Player 1 on the right, Player 2 on the left
+            self.paddle.set_x(WINDOW_WIDTH as i32 * 2 / 3 - self.paddle.width / 2);
+            self.paddle.last_x = self.paddle.x;
+            paddle2.set_x(WINDOW_WIDTH as i32 / 3 - paddle2.width / 2);
+            paddle2.last_x = paddle2.x;
+            self.paddle2 = Some(paddle2);
+        }
+
+        if self.mode == GameMode::Versus {
+            // Player 2 defends the top edge, mirroring Player 1
+            let mut paddle2 = Paddle::new();
+            self.modifiers.setup_paddle(&mut paddle2);
+            paddle2.y = WINDOW_HEIGHT as i32 - self.paddle.y - PADDLE_HEIGHT;
+            self.paddle2 = Some(paddle2);
+            self.balls = vec![self.ball_on_paddle(0), self.ball_on_paddle(1)];
+            return;
+        }
+
+        self.balls = vec![self.ball_on_paddle(0)];
+    }
+
+    /// Paddle controlled by a player (Player 2 falls back to Player 1 outside co-op)
+    pub fn paddle_of(&self, player: usize) -> &Paddle {
+        match (player, &self.paddle2) {
+            (1, Some(paddle2)) => paddle2,
+            _ => &self.paddle,
+        }
+    }
+
+    fn paddle_of_mut(&mut self, player: usize) -> &mut Paddle {
+        match (player, &mut self.paddle2) {
+            (1, Some(paddle2)) => paddle2,
+            _ => &mut self.paddle,
+        }
+    }
+
+    /// Fresh ball resting on a player's paddle
+    fn ball_on_paddle(&self, player: usize) -> Ball {
+        let mut ball = Ball::new(0.0, 0.0);
+        place_on_paddle(&mut ball, self.paddle_of(player));
+        ball.owner = player;
+        ball
+    }
+
+    /// Co-op: push overlapping paddles apart so they never pass through each other
+    fn separate_paddles(&mut self) {
+        if self.mode != GameMode::CoOp {
+            return;
+        }
+        let Some(ref mut paddle2) = self.paddle2 else {
+            return;
+        };
+
+        let (left, right) = if self.paddle.x + self.paddle.width / 2 <= paddle2.x + paddle2.width / 2 {
+            (&mut self.paddle, paddle2)
+        } else {
+            (paddle2, &mut self.paddle)
+        };
+
+        let overlap = left.x + left.width - right.x;
+        if overlap > 0 {
+            left.x -= overlap / 2;
+            right.x += overlap - overlap / 2;
+
+            // Walls win - whoever is pinned pushes the other back
+            left.x = left.x.max(0);
+            right.x = right.x.max(left.x + left.width);
+            right.x = right.x.min(WINDOW_WIDTH as i32 - right.width);
+            left.x = left.x.min(right.x - left.width);
         }
     }
 
-    pub fn reset(&mut self) {
-        *self = Game::new();
+    /// Hot-seat: hand control to the waiting player, unless their game is over
+    fn pass_turn(&mut self) {
+        let Some(mut other) = self.other_player.take() else {
+            return;
+        };
+
+        if other.state == GameState::GameOver {
+            // Nobody to pass to - keep playing (or stay on game over)
+            self.other_player = Some(other);
+            return;
+        }
+
+        // Finish the heart theft before parking this game
+        self.penguin = None;
+        self.stolen_heart_position = None;
+
+        std::mem::swap(self, &mut other);
+        self.other_player = Some(other);
+        self.state = GameState::PlayerReady;
+    }
+
+    /// Scores of both players, Player 1 first (hot-seat only)
+    pub fn hot_seat_scores(&self) -> Option<[u32; 2]> {
+        let other = self.other_player.as_ref()?;
+        let mut scores = [0; 2];
+        scores[self.player] = self.score;
+        scores[other.player] = other.score;
+        Some(scores)
+    }
+
+    /// Keyboard paddle movement. While a ball rests on the paddle this steers the aim instead.
+    pub fn move_paddle(&mut self, player: usize, direction: i32) {
+        let direction = self.modifiers.paddle_direction(direction);
+        if self.is_aiming(player) {
+            self.aim(player, direction);
+            return;
+        }
+        let paddle = self.paddle_of_mut(player);
+        if direction < 0 {
+            paddle.move_left();
+        } else {
+            paddle.move_right();
+        }
+    }
+
+    /// Mouse paddle movement - center the paddle on x, respecting modifiers
+    pub fn move_paddle_to(&mut self, player: usize, x: i32) {
+        let center_x = self.modifiers.paddle_target(x);
+        let paddle = self.paddle_of_mut(player);
+        paddle.set_x(center_x - paddle.width / 2);
+    }
+
+    /// Autopilot paddle movement - head for a center x at keyboard speed.
+    /// Control modifiers (mirror) only remap human input, so they are not applied here.
+    pub fn steer_paddle(&mut self, player: usize, center_x: i32) {
+        let paddle = self.paddle_of_mut(player);
+        let step = (center_x - paddle.width / 2 - paddle.x).clamp(-PADDLE_SPEED, PADDLE_SPEED);
+        paddle.set_x(paddle.x + step);
+    }
+
+    /// A ball rests on this player's paddle, waiting to be launched
+    pub fn has_resting_ball(&self, player: usize) -> bool {
+        self.balls.iter().any(|b| b.attached_to_paddle && b.owner == player)
+    }
+
+    /// Input aims instead of moving the paddle: a ball rests on it and none of
+    /// this player's balls is in play (a caught Extra Ball mid-rally keeps the paddle moving)
+    pub fn is_aiming(&self, player: usize) -> bool {
+        self.has_resting_ball(player)
+            && !self.balls.iter().any(|b| !b.attached_to_paddle && b.owner == player)
+    }
+
+    /// Turn the launch aim of this player's resting balls (keys)
+    pub fn aim(&mut self, player: usize, direction: i32) {
+        for ball in self.balls.iter_mut().filter(|b| b.attached_to_paddle && b.owner == player) {
+            ball.set_aim(ball.aim + direction.signum() as f32 * AIM_SPEED);
+            ball.launch_timer = ball.launch_timer.max(AIM_HOLD_FRAMES); // Don't fire mid-aim
+        }
+    }
+
+    /// Point the launch aim of this player's resting balls at a spot (mouse)
+    pub fn aim_at(&mut self, player: usize, x: i32, y: i32) {
+        let top = self.paddle_of(player).is_top();
+        for ball in self.balls.iter_mut().filter(|b| b.attached_to_paddle && b.owner == player) {
+            let dx = x as f32 - (ball.x + BALL_SIZE as f32 / 2.0);
+            let dy = y as f32 - (ball.y + BALL_SIZE as f32 / 2.0);
+            // Distance away from the paddle - up for the bottom paddle, down for the top one
+            let away = if top { dy } else { -dy };
+            ball.set_aim(dx.atan2(away.max(1.0)));
+            ball.launch_timer = ball.launch_timer.max(AIM_HOLD_FRAMES);
+        }
+    }
+
+    /// Dotted launch path for a resting ball, up to a little past its first wall or block bounce
+    pub fn aim_preview(&self, ball: &Ball) -> Vec<(f32, f32)> {
+        let (vel_x, mut vel_y) = ball.launch_velocity();
+        if self.paddle_of(ball.owner).is_top() {
+            vel_y = -vel_y;
+        }
+        let speed = (vel_x * vel_x + vel_y * vel_y).sqrt();
+        let (mut dir_x, mut dir_y) = (vel_x / speed, vel_y / speed);
+
+        let ghost = self.paddle_of(ball.owner).has_effect(EffectKind::GhostBall);
+        let max_x = (WINDOW_WIDTH as i32 - BALL_SIZE) as f32;
+        let (mut x, mut y) = (ball.x, ball.y);
+        let mut points = Vec::new();
+        let mut bounced_at = None;
+
+        // March in small steps, dropping a dot every few
+        for step in 0..AIM_PREVIEW_STEPS {
+            x += dir_x * 4.0;
+            y += dir_y * 4.0;
+
+            let ball_rect = Rect::new(x as i32, y as i32, BALL_SIZE as u32, BALL_SIZE as u32);
+            let mut bounced = false;
+            if x <= 0.0 || x >= max_x {
+                dir_x = -dir_x;
+                bounced = true;
+            } else if y <= 0.0 && self.mode != GameMode::Versus {
+                dir_y = -dir_y;
+                bounced = true;
+            } else if y <= -(BALL_SIZE as f32) || y >= WINDOW_HEIGHT as f32 {
+                break; // Off the playfield
+            } else if let Some(block) = self.blocks.iter().find(|b| b.active && !ghost && b.rect().has_intersection(ball_rect)) {
+                // Reflect off the side the path came in through
+                let overlap = block.rect().intersection(ball_rect).unwrap_or(ball_rect);
+                if overlap.width() < overlap.height() {
+                    dir_x = -dir_x;
+                } else {
+                    dir_y = -dir_y;
+                }
+                bounced = true;
+            }
+
+            if bounced {
+                if bounced_at.is_some() {
+                    break; // Only the first bounce is shown
+                }
+                bounced_at = Some(step);
+                x += dir_x * 4.0;
+                y += dir_y * 4.0;
+            }
+
+            if bounced_at.is_some_and(|at| step - at > AIM_PREVIEW_AFTER_BOUNCE) {
+                break;
+            }
+            if step % 4 == 0 {
+                points.push((x + BALL_SIZE as f32 / 2.0, y + BALL_SIZE as f32 / 2.0));
+            }
+        }
+
+        points
+    }
+
+    /// Space bar: launch this player's resting balls, otherwise fire a rocket
+    pub fn press_action(&mut self, player: usize, play_sound: &mut dyn FnMut(SoundEffect)) {
+        if self.has_resting_ball(player) {
+            self.launch_balls(player);
+        } else {
+            self.fire_rocket(player, play_sound);
+        }
     }
 
     pub fn next_level(&mut self) {
         // Restore 1 life if lost during this level (up to max 3)
-        if self.lost_life_this_level && self.lives < 3 {
+        if self.lost_life_this_level && self.lives < self.modifiers.max_lives() {
             self.lives += 1;
         }
+
+        // Tally the end-of-level bonuses
+        self.stats.top_speed = self.max_speed;
+        let par_seconds = if self.daily.is_none() && is_boss_level(self.current_level) {
+            120
+        } else {
+            self.level_meta.par_seconds.unwrap_or(30 + self.stats.blocks_destroyed())
+        };
+        self.results = LevelResults::new(self.stats, self.best_combo, par_seconds);
+        self.results.scale(self.modifiers.score_multiplier());
+        self.score += self.results.total();
+
+        if let Some(ref mut time_attack) = self.time_attack {
+            time_attack.finish_level();
+            if self.current_level >= TIME_ATTACK_LEVELS.min(campaign().last_level()) {
+                time_attack.finish_run();
+                self.state = GameState::Victory;
+                return;
+            }
+        }
+
+        if let Some(ref daily) = self.daily {
+            let finished = self.current_level >= DAILY_LEVELS;
+            daily.record(self.score, self.current_level, finished);
+            self.state = if finished { GameState::Victory } else { GameState::LevelTransition };
+            return;
+        }
         
-        if self.current_level == 9 {
+        if self.current_level == campaign().last_level() {
             self.state = GameState::Victory;
         } else {
             self.state = GameState::LevelTransition;
         }
     }    
     pub fn start_next_level(&mut self) {
+        if self.warp_pending {
+            self.warp_pending = false;
+            if self.start_bonus_stage() {
+                return;
+            }
+        }
+
         self.current_level += 1;
-        self.paddle = Paddle::new();
-        // Ball starts on top of paddle
-        self.balls = vec![Ball::new(
-            self.paddle.x as f32 + self.paddle.width as f32 / 2.0 - BALL_SIZE as f32 / 2.0,
-            self.paddle.y as f32 - BALL_SIZE as f32,
-        )];
+        self.apply_level_modifiers();
+        self.load_level();
+        self.reset_level_state();
+    }
+
+    /// Blocks, level file info and boss of the current level
+    fn load_level(&mut self) {
+        if let Some(ref daily) = self.daily {
+            self.blocks = generate_builtin_blocks(daily.layout(self.current_level), SpecialOdds::for_level(daily.difficulty(self.current_level)));
+            self.level_meta = PatternMeta::default();
+            self.boss = None;
+            return;
+        }
+
         self.blocks = create_blocks(self.current_level);
+        self.level_meta = level_meta(self.current_level);
+        self.boss = if is_boss_level(self.current_level) { Some(Boss::new(self.current_level)) } else { None };
+    }
+
+    /// The portal closed the last level - the next click warps to a bonus stage
+    pub fn is_warp_pending(&self) -> bool {
+        self.warp_pending
+    }
+
+    /// Portal warp: a short bonus stage from the campaign, then on to the next level.
+    /// False if the campaign has no bonus stages.
+    fn start_bonus_stage(&mut self) -> bool {
+        let Some(def) = BonusStage::choose(campaign(), &self.portal, self.current_level) else {
+            return false;
+        };
+        let stage = BonusStage::new(def, &self.portal);
+        self.blocks = stage.create_blocks();
+        self.boss = None;
+        self.reset_level_state();
+        self.bonus_stage = Some(stage);
+        true
+    }
+
+    /// Start an editor test run with the given blocks
+    pub fn start_test_level(&mut self, blocks: Vec<Block>) {
+        self.blocks = blocks;
+        self.boss = None;
+        self.score = 0;
+        self.lives = self.modifiers.max_lives();
+        self.is_test_mode = true;
+        self.reset_level_state();
+    }
+
+    /// Fresh paddle, ball and per-level bookkeeping for a new playfield
+    fn reset_level_state(&mut self) {
+        self.reset_paddle();
         self.bonuses.clear();
         self.particles.clear();
+        self.shockwaves.clear();
         self.rockets.clear();
         self.penguin = None;
         self.stolen_heart_position = None;
+        self.bonus_stage = None;
         self.state = GameState::Playing;
         self.lost_life_this_level = false; // Reset flag for new level
         self.portal_active = false; // Reset portal for new level
         self.portal_completion_timer = 0; // Reset timer for new level
         self.max_speed = 0.0; // Reset max speed so portal can trigger again
+        self.boss_projectiles.clear();
+        self.combo = 0;
+        self.best_combo = 0;
+        self.score_popups.clear();
+        self.stats = LevelStats::default();
     }
 
-    pub fn get_background_path(&self) -> String {
-        format!("assets/background{}.png", self.current_level)
+    /// Campaign entry for the current level, None in infinite mode and daily runs
+    pub fn level_def(&self) -> Option<&'static CampaignLevel> {
+        if self.daily.is_some() {
+            return None;
+        }
+        campaign().level(self.current_level)
     }
 
-    pub fn fire_rocket(&mut self, play_sound: &mut dyn FnMut(SoundEffect)) {
-        if self.paddle.rocket_ammo > 0 {
-            self.paddle.rocket_ammo -= 1;
+    /// Infinite and daily levels get a seeded procedural theme, bonus stages their own
+    pub fn background(&self) -> LevelBackground {
+        if let Some(ref stage) = self.bonus_stage {
+            return stage.def.level.background.clone();
+        }
+        let theme = match self.daily {
+            Some(ref daily) => daily.layout(self.current_level),
+            None => self.current_level,
+        };
+        self.level_def()
+            .map(|def| def.background.clone())
+            .unwrap_or(LevelBackground::Theme(theme))
+    }
+
+    /// Music track for the current level (campaign first, then the level file),
+    /// None for the shuffled playlist
+    pub fn level_music(&self) -> Option<&str> {
+        if let Some(ref stage) = self.bonus_stage {
+            return stage.def.level.music.as_deref();
+        }
+        self.level_def()
+            .and_then(|def| def.music.as_deref())
+            .or(self.level_meta.music.as_deref())
+    }
+
+    pub fn fire_rocket(&mut self, player: usize, play_sound: &mut dyn FnMut(SoundEffect)) {
+        let paddle = self.paddle_of_mut(player);
+        if paddle.take_rocket() {
             // Spawn rocket at center of paddle
-            self.rockets.push(Rocket::new(
-                self.paddle.x as f32 + self.paddle.width as f32 / 2.0 - 5.0,
-                self.paddle.y as f32 - 20.0,
-            ));
+            let mut rocket = Rocket::new(
+                paddle.x as f32 + paddle.width as f32 / 2.0 - 5.0,
+                paddle.y as f32 - 20.0,
+            );
+            if paddle.is_top() {
+                // Top paddle fires down at the wall
+                rocket.y = (paddle.y + PADDLE_HEIGHT) as f32;
+                rocket.vel_y = -rocket.vel_y;
+            }
+            rocket.owner = player;
+            self.rockets.push(rocket);
             play_sound(SoundEffect::Load);
         }
     }
     
-    pub fn launch_balls(&mut self) {
+    /// Launch the balls resting on a player's paddle
+    pub fn launch_balls(&mut self, player: usize) {
         use rand::Rng;
-        let mut rng = rand::thread_rng();
+        let mut rng = rng::game_rng();
         
+        let serve_down = self.paddle_of(player).is_top();
         for ball in &mut self.balls {
-            if ball.attached_to_paddle {
+            if ball.attached_to_paddle && ball.owner == player {
                 ball.launch();
+                if serve_down {
+                    ball.vel_y = ball.vel_y.abs();
+                }
                 
                 // Create particle burst effect at launch
                 let cx = ball.x + BALL_SIZE as f32 / 2.0;
@@ -165,22 +706,53 @@ impl Game {
     }
 
     pub fn update(&mut self, play_sound: &mut dyn FnMut(SoundEffect)) {
+        // Results screen counts up on its own
+        if self.state == GameState::LevelTransition {
+            self.results.update();
+        }
+
         if self.state != GameState::Playing {
             return;
         }
         
         // Increment frame counter for animations
         self.frame_count = self.frame_count.wrapping_add(1);
+        self.stats.frames += 1;
+        if let Some(ref mut stage) = self.bonus_stage {
+            if let Some(bonus) = stage.update() {
+                self.bonuses.push(bonus);
+            }
+        }
+
+        if let Some(ref mut time_attack) = self.time_attack {
+            time_attack.tick();
+        }
         
         // Increment bonus cooldown
         self.bonus_cooldown = self.bonus_cooldown.saturating_add(1);
 
-        // Update paddle
+        // Update paddles
+        self.separate_paddles();
         self.paddle.update();
+        if let Some(ref mut paddle2) = self.paddle2 {
+            paddle2.update();
+        }
+
+        // Update boss (moves and fires at the paddle - in co-op it switches target every 5 seconds)
+        let target_player = if self.paddle2.is_some() { (self.frame_count / 300 % 2) as usize } else { 0 };
+        let target = self.paddle_of(target_player);
+        let (target_x, target_y) = (target.x as f32 + target.width as f32 / 2.0, target.y as f32);
+        if let Some(ref mut boss) = self.boss {
+            let fired = boss.update(target_x, target_y);
+            self.boss_projectiles.extend(fired);
+        }
 
         // Track particles to spawn
         let mut particles_to_spawn = Vec::new();
         let mut portal_just_activated = false;
+        let mut boss_events = Vec::new();
+        let mut destroyed_blocks = Vec::new(); // Block centers, sources and players, scored after collisions
+        let mut explosions = Vec::new(); // Explosive blocks set off this frame and who hit them
 
         // Update balls
         for (i, ball) in self.balls.iter_mut().enumerate() {
@@ -202,12 +774,28 @@ impl Game {
             }
 
             // If ball is attached to paddle, keep it on the paddle
+            let owner_paddle = match (ball.owner, &self.paddle2) {
+                (1, Some(paddle2)) => paddle2,
+                _ => &self.paddle,
+            };
             if ball.attached_to_paddle {
-                ball.x = self.paddle.x as f32 + self.paddle.width as f32 / 2.0 - BALL_SIZE as f32 / 2.0;
-                ball.y = self.paddle.y as f32 - BALL_SIZE as f32;
+                place_on_paddle(ball, owner_paddle);
+
+                // Count down to auto-launch (unless turned off in settings)
+                if self.auto_launch {
+                    if ball.launch_timer > 0 {
+                        ball.launch_timer -= 1;
+                    } else {
+                        ball.launch();
+                        // Auto-launch from the top paddle serves downward
+                        if owner_paddle.is_top() {
+                            ball.vel_y = ball.vel_y.abs();
+                        }
+                    }
+                }
             }
 
-            ball.update(self.gravity_mode);
+            ball.update(&self.modifiers, self.mode == GameMode::Versus);
             
             // Calculate current speed
             let speed_px_frame = (ball.vel_x.powi(2) + ball.vel_y.powi(2)).sqrt();
@@ -231,7 +819,7 @@ impl Game {
                     
                     // Spawn a few particles behind the ball to form a trail
                     for _ in 0..5 {
-                        let mut rng = rand::thread_rng();
+                        let mut rng = rng::game_rng();
                         
                         // Spread angle slightly for "wave" look
                         let spread_angle = (rng.gen::<f32>() - 0.5) * 1.0; // +/- 0.5 radians
@@ -256,8 +844,14 @@ impl Game {
                     }
                 }
                 
-                // Activate portal at 3600 px/s (only once per level)
-                if self.max_speed >= 3600.0 && !self.portal_active {
+                // Activate portal at the threshold speed (only once per level, never during a boss fight,
+                // a bonus stage or versus)
+                if self.max_speed >= self.portal.threshold
+                    && !self.portal_active
+                    && self.boss.is_none()
+                    && self.bonus_stage.is_none()
+                    && self.mode != GameMode::Versus
+                {
                     self.portal_active = true;
                     portal_just_activated = true;
                     
@@ -266,7 +860,7 @@ impl Game {
                     let portal_y = WINDOW_HEIGHT as f32 / 2.0;
                     
                     for _ in 0..100 {
-                        let mut rng = rand::thread_rng();
+                        let mut rng = rng::game_rng();
                         let angle = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
                         let speed = rng.gen::<f32>() * 15.0 + 5.0;
                         
@@ -344,38 +938,45 @@ impl Game {
         }
 
         // Paddle and block collisions (per ball, still inside original ball iteration context)
+        let bonus_table = match self.bonus_stage {
+            Some(ref stage) => stage.def.level.bonus_table,
+            None => self.level_def().and_then(|def| def.bonus_table),
+        }
+        .unwrap_or_default();
         for ball in &mut self.balls {
-            // Paddle collision
+            // Paddle collision - the touching paddle takes ownership of the ball
+            let mut hit_by = None;
             if ball.active && check_collision(ball.rect(), self.paddle.rect()) {
-                ball.vel_y = -ball.vel_y.abs();
-                // Add horizontal velocity based on where ball hits paddle
-                let paddle_center = self.paddle.x + self.paddle.width / 2;
-                let ball_center = ball.x as i32 + BALL_SIZE / 2;
-                let offset = ball_center - paddle_center;
-                ball.vel_x += offset as f32 * 0.1;
-                
-                // Add spin based on paddle velocity and offset
-                // REFINED: Less sensitive, requires minimum velocity
-                let paddle_vel = self.paddle.vel_x as f32;
-                
-                if paddle_vel.abs() > 2.0 {
-                    // Only apply spin if moving fast enough
-                    ball.spin = (paddle_vel * 0.3) + (offset as f32 * 0.05);
-                    // Trigger visual discharge effect
-                    self.paddle.spin_intensity = 1.0;
-                } else {
-                    // Minimal spin from just position offset
-                    ball.spin = offset as f32 * 0.02;
+                bounce_off_paddle(ball, &mut self.paddle);
+                hit_by = Some(0);
+            } else if let Some(ref mut paddle2) = self.paddle2 {
+                if ball.active && check_collision(ball.rect(), paddle2.rect()) {
+                    bounce_off_paddle(ball, paddle2);
+                    hit_by = Some(1);
                 }
-                
+            }
+
+            if let Some(player) = hit_by {
+                ball.owner = player;
+
                 // Scoring: +5 points for reflecting ball
-                self.score += 5;
+                let points = self.modifiers.scale_points(5);
+                self.score += points;
+                self.player_scores[player] += points;
+                self.stats.paddle_hits += 1;
+                // Paddle touch ends the combo
+                self.combo = 0;
                 play_sound(SoundEffect::Bounce);
             }
 
 
+            // Ghost mode belongs to the paddle that owns the ball
+            let ghost = match (ball.owner, &self.paddle2) {
+                (1, Some(paddle2)) => paddle2.has_effect(EffectKind::GhostBall),
+                _ => self.paddle.has_effect(EffectKind::GhostBall),
+            };
+
             // Block collision
-            let mut explosions = Vec::new();
             for block in &mut self.blocks {
                 if !block.active || !ball.active {
                     continue;
@@ -401,7 +1002,14 @@ impl Game {
                             true // Explodes immediately
                         },
                         BlockType::Normal => {
-                            true
+                            // Usually one hit - level files can ask for more
+                            block.health = block.health.saturating_sub(1);
+                            if block.health == 0 {
+                                true
+                            } else {
+                                play_sound(SoundEffect::Bounce);
+                                false
+                            }
                         }
                     };
 
@@ -412,7 +1020,7 @@ impl Game {
                     // Collision Resolution
                     // If ghost mode is ON, we pass through EVERYTHING (no bounce).
                     // Otherwise (ghost mode OFF), we bounce off EVERYTHING (even if destroyed).
-                    let should_bounce = self.paddle.ghost_timer == 0;
+                    let should_bounce = !ghost;
                     
                     if should_bounce {
                         // Determine collision side based on overlap dimensions
@@ -420,7 +1028,7 @@ impl Game {
                         if overlap.width() < overlap.height() {
                             // Horizontal Collision (Side hit)
                             // Push ball out horizontally
-                            if ball.x + (BALL_SIZE as f32 / 2.0) < block.x as f32 + (BLOCK_WIDTH as f32 / 2.0) {
+                            if ball.x + (BALL_SIZE as f32 / 2.0) < block.x as f32 + (block.width as f32 / 2.0) {
                                 // Hit from left
                                 ball.x -= overlap.width() as f32;
                             } else {
@@ -431,7 +1039,7 @@ impl Game {
                         } else {
                             // Vertical Collision (Top/Bottom hit)
                             // Push ball out vertically
-                            if ball.y + (BALL_SIZE as f32 / 2.0) < block.y as f32 + (BLOCK_HEIGHT as f32 / 2.0) {
+                            if ball.y + (BALL_SIZE as f32 / 2.0) < block.y as f32 + (block.height as f32 / 2.0) {
                                 // Hit from top
                                 ball.y -= overlap.height() as f32;
                             } else {
@@ -443,13 +1051,18 @@ impl Game {
                     }
                     
                     if destroyed {
-                        self.score += 10;
+                        destroyed_blocks.push((
+                            block.x as f32 + block.width as f32 / 2.0,
+                            block.y as f32 + block.height as f32 / 2.0,
+                            DestroySource::Ball,
+                            ball.owner,
+                        ));
                         play_sound(SoundEffect::Bounce);
 
                         // Queue particles to spawn
                         particles_to_spawn.push((
-                            block.x as f32 + BLOCK_WIDTH as f32 / 2.0,
-                            block.y as f32 + BLOCK_HEIGHT as f32 / 2.0,
+                            block.x as f32 + block.width as f32 / 2.0,
+                            block.y as f32 + block.height as f32 / 2.0,
                             block.color,
                         ));
                         
@@ -457,34 +1070,42 @@ impl Game {
                         if block.block_type == BlockType::Explosive {
                              // Explosion radius logic (2 blocks radius approx 120px)
                             let explosion_center = (
-                                block.x as f32 + BLOCK_WIDTH as f32 / 2.0,
-                                block.y as f32 + BLOCK_HEIGHT as f32 / 2.0,
+                                block.x as f32 + block.width as f32 / 2.0,
+                                block.y as f32 + block.height as f32 / 2.0,
                             );
-                            explosions.push(explosion_center);
+                            explosions.push((explosion_center.0, explosion_center.1, ball.owner));
                         }
                     }
 
                     // Random bonus drop (15% chance) with 1-second cooldown
                     // Only drop bonuses from destroyed blocks
                     if destroyed {
-                        let mut rng = rand::thread_rng();
+                        let mut rng = rng::game_rng();
                         let cooldown_frames = 60; // 1 seconds at 60 FPS
                         
-                        if rng.gen::<f32>() < 0.15 && self.bonus_cooldown >= cooldown_frames {
-                            // Weighted bonus distribution:
-                            // LongPaddle: 50%, ExtraBall: 25%, GhostBall: 15%, Rocket: 10%
-                            let bonus_type = match rng.gen_range(0..100) {
-                                0..=49 => BonusType::LongPaddle,     // 40%
-                                50..=74 => BonusType::ExtraBall,     // 35%
-                                75..=89 => BonusType::GhostBall,     // 15%
-                                90..=99 => BonusType::Rocket,        // 10%
-                                _ => BonusType::LongPaddle,          // Fallback to most common
-                            };
-                            self.bonuses.push(Bonus::new(
-                                block.x as f32 + BLOCK_WIDTH as f32 / 2.0,
+                        // Blocks with a set drop always release it
+                        if block.drop.is_some() || (rng.gen::<f32>() < 0.15 && self.bonus_cooldown >= cooldown_frames) {
+                            // Weighted bonus distribution from the level's bonus table
+                            let bonus_type = block.drop.unwrap_or_else(|| bonus_table.roll(&mut rng));
+                            let mut bonus = Bonus::new(
+                                block.x as f32 + block.width as f32 / 2.0,
                                 block.y as f32,
                                 bonus_type,
-                            ));
+                            );
+
+                            // Versus: capsules fly to the player who broke the block,
+                            // but 1 in 3 is a nasty one sent to the opponent instead
+                            if self.mode == GameMode::Versus {
+                                let mut receiver = ball.owner;
+                                if rng.gen_range(0..3) == 0 {
+                                    bonus.bonus_type = if rng.gen() { BonusType::Shrink } else { BonusType::SpeedUp };
+                                    receiver = 1 - ball.owner;
+                                }
+                                if receiver == 1 {
+                                    bonus.vel_y = -bonus.vel_y;
+                                }
+                            }
+                            self.bonuses.push(bonus);
                             // Reset cooldown timer
                             self.bonus_cooldown = 0;
                         }
@@ -492,57 +1113,65 @@ impl Game {
                     
                     // If not ghost mode, break after first collision to prevent destroying multiple blocks in one frame
                     // unless we want to allow corner hits. Standard breakout behavior is break.
-                    if self.paddle.ghost_timer == 0 {
+                    if !ghost {
                         break;
                     }
                 }
             }
 
-            // Process explosions
-            for (exp_x, exp_y) in explosions {
-                // Explosion radius: 120px (approx 2 blocks)
-                let radius_sq = 60.0 * 60.0;
-                
-                for block in &mut self.blocks {
-                    if !block.active {
-                        continue;
-                    }
-                    
-                    let block_center_x = block.x as f32 + BLOCK_WIDTH as f32 / 2.0;
-                    let block_center_y = block.y as f32 + BLOCK_HEIGHT as f32 / 2.0;
-                    
-                    let dx = block_center_x - exp_x;
-                    let dy = block_center_y - exp_y;
-                    let dist_sq = dx*dx + dy*dy;
-                    
-                    if dist_sq <= radius_sq {
-                        // Destroy block
-                        block.active = false;
-                        self.score += 10;
-                        
-                        // Add particles for destroyed block
-                        particles_to_spawn.push((
-                            block_center_x,
-                            block_center_y,
-                            block.color,
-                        ));
+            // Boss collision - the boss is solid, even ghost balls bounce off it
+            if let Some(ref mut boss) = self.boss {
+                if ball.active && !boss.is_defeated() {
+                    if let Some(overlap) = ball.rect().intersection(boss.rect()) {
+                        let (boss_cx, boss_cy) = boss.center();
+                        let ball_cx = ball.x + BALL_SIZE as f32 / 2.0;
+                        let ball_cy = ball.y + BALL_SIZE as f32 / 2.0;
+
+                        if overlap.width() < overlap.height() {
+                            // Side hit
+                            if ball_cx < boss_cx {
+                                ball.x -= overlap.width() as f32;
+                                ball.vel_x = -ball.vel_x.abs();
+                            } else {
+                                ball.x += overlap.width() as f32;
+                                ball.vel_x = ball.vel_x.abs();
+                            }
+                        } else if ball_cy < boss_cy {
+                            // Top hit
+                            ball.y -= overlap.height() as f32;
+                            ball.vel_y = -ball.vel_y.abs();
+                        } else {
+                            // Bottom hit
+                            ball.y += overlap.height() as f32;
+                            ball.vel_y = ball.vel_y.abs();
+                        }
+
+                        boss_events.push(boss.take_damage(1));
+                        particles_to_spawn.push((ball_cx, ball_cy, Color { r: 255, g: 80, b: 200 }));
                     }
                 }
-                
-                // Play explosion sound
-                play_sound(SoundEffect::Explosion);
             }
         }
 
         if portal_just_activated {
             // self.balls.clear(); // Don't remove balls, let them orbit
-            self.score += 5000;
+            self.score += self.modifiers.scale_points(self.portal.points);
         }
 
         // Update Rockets
         for rocket in &mut self.rockets {
             rocket.update();
             
+            // Rockets hit the boss hard
+            if let Some(ref mut boss) = self.boss {
+                if rocket.active && !boss.is_defeated() && check_collision(rocket.rect(), boss.rect()) {
+                    rocket.active = false;
+                    boss_events.push(boss.take_damage(3));
+                    particles_to_spawn.push((rocket.x, rocket.y, Color { r: 255, g: 100, b: 0 }));
+                    play_sound(SoundEffect::BreakingGlass);
+                }
+            }
+
             if rocket.active {
                 // Check collision with blocks
                 let mut hit_block = false;
@@ -553,10 +1182,10 @@ impl Game {
                         block.active = false;
                         hit_block = true;
                         explosion_center = (
-                            block.x as f32 + BLOCK_WIDTH as f32 / 2.0,
-                            block.y as f32 + BLOCK_HEIGHT as f32 / 2.0,
+                            block.x as f32 + block.width as f32 / 2.0,
+                            block.y as f32 + block.height as f32 / 2.0,
                         );
-                        self.score += 10;
+                        destroyed_blocks.push((explosion_center.0, explosion_center.1, DestroySource::Rocket, rocket.owner));
                         particles_to_spawn.push((explosion_center.0, explosion_center.1, block.color));
                         break; // Rocket hits one block then explodes
                     }
@@ -568,18 +1197,24 @@ impl Game {
                     
                     // Explosion radius logic (2 blocks radius approx 120px)
                     let radius = 120.0;
+                    self.shockwaves.push(Shockwave::new(explosion_center.0, explosion_center.1, radius + SHOCKWAVE_REACH));
                     for block in &mut self.blocks {
                         if block.active {
-                            let block_center_x = block.x as f32 + BLOCK_WIDTH as f32 / 2.0;
-                            let block_center_y = block.y as f32 + BLOCK_HEIGHT as f32 / 2.0;
+                            let block_center_x = block.x as f32 + block.width as f32 / 2.0;
+                            let block_center_y = block.y as f32 + block.height as f32 / 2.0;
                             let dx = block_center_x - explosion_center.0;
                             let dy = block_center_y - explosion_center.1;
                             let dist = (dx*dx + dy*dy).sqrt();
                             
                             if dist <= radius {
                                 block.active = false;
-                                self.score += 10;
+                                destroyed_blocks.push((block_center_x, block_center_y, DestroySource::Rocket, rocket.owner));
                                 particles_to_spawn.push((block_center_x, block_center_y, block.color));
+
+                                // Explosive blocks caught in the blast go off too
+                                if block.block_type == BlockType::Explosive {
+                                    explosions.push((block_center_x, block_center_y, rocket.owner));
+                                }
                             }
                         }
                     }
@@ -587,36 +1222,117 @@ impl Game {
             }
         }
 
+        // Blow up explosive blocks (and whatever they chain into)
+        self.detonate(explosions, &mut destroyed_blocks, &mut particles_to_spawn, play_sound);
+
         // Create all queued particles
         for (x, y, color) in particles_to_spawn {
             self.create_particles(x, y, color);
         }
 
+        // Score destroyed blocks (combo multiplier applies)
+        for (x, y, source, player) in destroyed_blocks {
+            self.stats.record_block(source);
+            self.award_block(x, y, player);
+        }
+
+        // React to boss hits
+        for event in boss_events {
+            self.handle_boss_event(event, play_sound);
+        }
+
+        // Update boss projectiles
+        let mut players_hit = Vec::new();
+        for projectile in &mut self.boss_projectiles {
+            projectile.update();
+
+            if projectile.active && check_collision(projectile.rect(), self.paddle.rect()) {
+                projectile.active = false;
+                players_hit.push(0);
+            } else if let Some(ref paddle2) = self.paddle2 {
+                if projectile.active && check_collision(projectile.rect(), paddle2.rect()) {
+                    projectile.active = false;
+                    players_hit.push(1);
+                }
+            }
+        }
+        self.boss_projectiles.retain(|p| p.active);
+
+        for player in players_hit {
+            let paddle = self.paddle_of_mut(player);
+            if paddle.has_effect(EffectKind::Shrink) {
+                // Already shrunk - this one costs a life
+                self.boss_projectiles.clear();
+                self.lose_life(play_sound);
+                break;
+            } else {
+                paddle.add_effect(EffectKind::Shrink);
+                play_sound(SoundEffect::BreakingGlass);
+            }
+        }
+
+        // Remove the boss once its death animation has played out
+        if self.boss.as_ref().is_some_and(|boss| boss.is_finished()) {
+            self.boss = None;
+        }
+
         // Update bonuses
+        let mut caught_bonuses = Vec::new();
         for bonus in &mut self.bonuses {
             bonus.update();
+            if !bonus.active {
+                self.stats.bonuses_missed += 1; // Fell off the bottom
+            }
 
-            // Check bonus collection
+            // Check bonus collection - the capsule goes to the paddle that caught it
             if bonus.active && check_collision(bonus.rect(), self.paddle.rect()) {
                 bonus.active = false;
-                self.score += 2; // Scoring: +2 points for bonus collection
-                
-                match bonus.bonus_type {
-                    BonusType::ExtraBall => {
-                        // Add a new ball
-                        self.balls.push(Ball::new(
-                            self.paddle.x as f32 + self.paddle.width as f32 / 2.0,
-                            self.paddle.y as f32 - 20.0,
-                        ));
-                    }
-                    BonusType::LongPaddle => {
-                        self.paddle.activate_long_bonus();
-                    }
-                    BonusType::GhostBall => {
-                        self.paddle.activate_ghost_bonus();
+                caught_bonuses.push((0, bonus.bonus_type, bonus.rescued));
+            } else if let Some(ref paddle2) = self.paddle2 {
+                if bonus.active && check_collision(bonus.rect(), paddle2.rect()) {
+                    bonus.active = false;
+                    caught_bonuses.push((1, bonus.bonus_type, bonus.rescued));
+                }
+            }
+        }
+
+        for (player, bonus_type, rescued) in caught_bonuses {
+            // Scoring: +2 points for bonus collection, much more in a bonus stage
+            let base = if self.bonus_stage.is_some() { self.portal.capsule_points } else { 2 };
+            let points = self.modifiers.scale_points(base);
+            self.score += points;
+            self.player_scores[player] += points;
+            self.stats.bonuses_caught += 1;
+
+            match bonus_type {
+                BonusType::ExtraBall => {
+                    // Add a new ball
+                    let ball = self.ball_on_paddle(player);
+                    self.balls.push(ball);
+                }
+                BonusType::LongPaddle => {
+                    self.paddle_of_mut(player).add_effect(EffectKind::LongPaddle);
+                }
+                BonusType::GhostBall => {
+                    self.paddle_of_mut(player).add_effect(EffectKind::GhostBall);
+                }
+                BonusType::Rocket => {
+                    self.paddle_of_mut(player).add_effect(EffectKind::Rockets);
+                }
+                BonusType::Shrink => {
+                    self.paddle_of_mut(player).add_effect(EffectKind::Shrink);
+                    play_sound(SoundEffect::BreakingGlass);
+                }
+                BonusType::SpeedUp => {
+                    for ball in &mut self.balls {
+                        ball.vel_x *= 1.3;
+                        ball.vel_y *= 1.3;
                     }
-                    BonusType::Rocket => {
-                        self.paddle.add_rockets();
+                }
+                BonusType::Heart => {
+                    self.lives = (self.lives + 1).min(self.modifiers.max_lives());
+                    if rescued {
+                        self.stats.hearts_rescued += 1;
                     }
                 }
             }
@@ -632,8 +1348,8 @@ impl Game {
             for block in &mut self.blocks {
                 if block.active {
                     all_blocks_consumed = false;
-                    let bx = block.x as f32 + BLOCK_WIDTH as f32 / 2.0;
-                    let by = block.y as f32 + BLOCK_HEIGHT as f32 / 2.0;
+                    let bx = block.x as f32 + block.width as f32 / 2.0;
+                    let by = block.y as f32 + block.height as f32 / 2.0;
                     
                     // Calculate direction to portal
                     let dx = portal_x - bx;
@@ -650,7 +1366,7 @@ impl Game {
                         
                         // Spawn purple particles
                         for _ in 0..5 {
-                            let mut rng = rand::thread_rng();
+                            let mut rng = rng::game_rng();
                             let angle = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
                             let speed = rng.gen::<f32>() * 3.0;
                             
@@ -673,6 +1389,11 @@ impl Game {
                 // Wait 4.5 seconds (270 frames at 60 FPS) for animation to finish
                 if self.portal_completion_timer >= 270 {
                     self.next_level();
+                    // Warp to a bonus stage after the results screen (not against the clock)
+                    self.warp_pending = self.portal.bonus_stage
+                        && self.state == GameState::LevelTransition
+                        && self.time_attack.is_none()
+                        && !self.is_test_mode;
                 }
             }
         }
@@ -685,6 +1406,31 @@ impl Game {
             if penguin.state == PenguinState::Grabbing && self.stolen_heart_position.is_some() {
                 self.stolen_heart_position = None;
             }
+
+            // Heart rescue: a ball or rocket knocks the heart loose
+            if penguin.is_catchable() {
+                let mut knocked = false;
+                for ball in self.balls.iter_mut().filter(|b| b.active && !b.attached_to_paddle) {
+                    if check_collision(ball.rect(), penguin.rect()) {
+                        ball.vel_y = -ball.vel_y;
+                        knocked = true;
+                        break;
+                    }
+                }
+                if !knocked {
+                    if let Some(rocket) = self.rockets.iter_mut().find(|r| r.active && check_collision(r.rect(), penguin.rect())) {
+                        rocket.active = false;
+                        knocked = true;
+                    }
+                }
+
+                if knocked {
+                    penguin.hit();
+                    let center = penguin.rect().center();
+                    self.bonuses.push(Bonus::rescued_heart(center.x() as f32 - 20.0, center.y() as f32));
+                    play_sound(SoundEffect::BreakingGlass);
+                }
+            }
             
             // Remove penguin when animation is done
             if penguin.is_done() {
@@ -697,61 +1443,310 @@ impl Game {
             particle.update();
         }
 
+        self.update_shockwaves();
+
+        // Update score popups
+        for popup in &mut self.score_popups {
+            popup.update();
+        }
+
+        // Versus: a ball past the top edge is Player 1's goal, past the bottom Player 2's
+        if self.mode == GameMode::Versus {
+            for ball in self.balls.iter().filter(|ball| !ball.active) {
+                let scorer = if ball.y < WINDOW_HEIGHT as f32 / 2.0 { 0 } else { 1 };
+                self.goals[scorer] += 1;
+                self.last_conceded = 1 - scorer;
+                play_sound(SoundEffect::Oh);
+            }
+            if self.goals.iter().any(|&goals| goals >= VERSUS_GOALS) {
+                self.state = GameState::GameOver;
+            }
+        }
+
         // Remove inactive elements
+        let last_owner = self.balls.iter().rev().find(|ball| !ball.active).map_or(0, |ball| ball.owner);
         self.balls.retain(|ball| ball.active);
         self.bonuses.retain(|bonus| bonus.active);
         self.particles.retain(|p| p.is_alive());
+        self.shockwaves.retain(|s| s.is_alive());
+        self.score_popups.retain(|p| p.is_alive());
         self.rockets.retain(|r| r.active);
 
-        // Check if all balls are gone (only if portal is not active)
-        if self.balls.is_empty() && !self.portal_active {
-            self.lives -= 1;
-            self.lost_life_this_level = true; // Mark that a life was lost this level
-            
-            // Scoring: -20 points for losing life (ensure score doesn't go negative)
-            if self.score >= 20 {
-                self.score -= 20;
-            } else {
-                self.score = 0;
+        // Versus: no lives - whoever conceded serves the next ball
+        if self.mode == GameMode::Versus {
+            if self.balls.is_empty() && self.state == GameState::Playing {
+                let ball = self.ball_on_paddle(self.last_conceded);
+                self.balls.push(ball);
             }
-            
-            play_sound(SoundEffect::Oh);
-            
-            // Penguin animation instead of heart shatter particles
-            // Calculate position of the lost heart (it was at index self.lives)
-            // Logic: WINDOW_WIDTH - 30 - index * 25
-            // Since we just decremented lives, the lost heart index is the current self.lives value
-            // e.g. had 3 lives (indices 0,1,2). Lost one -> lives=2. Lost heart was at index 2.
-            let heart_x = WINDOW_WIDTH as f32 - 30.0 - (self.lives as f32 * 25.0);
-            let heart_y = 25.0; // Heart center Y position
-            
-            // Store the stolen heart position so it stays visible
-            self.stolen_heart_position = Some((heart_x, heart_y));
-            
-            // Spawn penguin to steal the heart
-            self.penguin = Some(Penguin::new(heart_x, heart_y));
 
+            // Rebuild the wall once it has been broken down
+            if self.blocks.iter().all(|block| !block.active || block.block_type == BlockType::Undestroyable) {
+                self.blocks = create_versus_blocks();
+            }
+            return;
+        }
 
-            if self.lives == 0 {
-                self.state = GameState::GameOver;
-            } else {
-                // Spawn new ball on paddle
-                self.balls.push(Ball::new(
-                    self.paddle.x as f32 + self.paddle.width as f32 / 2.0 - BALL_SIZE as f32 / 2.0,
-                    self.paddle.y as f32 - BALL_SIZE as f32,
-                ));
+        // Bonus stage: nothing at stake - a lost ball comes straight back.
+        // Ends when time is up or the block field is cleared.
+        if let Some(ref stage) = self.bonus_stage {
+            let field_cleared = !self.blocks.is_empty()
+                && self.blocks.iter().all(|block| !block.active || block.block_type == BlockType::Undestroyable);
+            if self.state == GameState::Playing && (stage.is_over() || field_cleared) {
+                self.start_next_level();
+                return;
             }
+            if self.balls.is_empty() {
+                let ball = self.ball_on_paddle(last_owner);
+                self.balls.push(ball);
+            }
+            return;
+        }
+
+        // Check if all balls are gone (only if portal is not active)
+        if self.balls.is_empty() && !self.portal_active && self.state == GameState::Playing {
+            self.lose_life(play_sound);
+
+            if self.lives > 0 {
+                // Spawn new ball on the paddle of whoever dropped it
+                let ball = self.ball_on_paddle(last_owner);
+                self.balls.push(ball);
+            }
+        }
+
+        // Hot-seat turn change after a lost life
+        if self.turn_over {
+            self.turn_over = false;
+            self.pass_turn();
+            return;
         }
 
         // Check if all destroyable blocks are destroyed (only if portal is not active)
         // If portal is active, it handles the transition after animation
-        if !self.portal_active && self.blocks.iter().all(|block| !block.active || block.block_type == BlockType::Undestroyable) {
+        // Boss levels are only complete once the boss is gone
+        if self.state == GameState::Playing
+            && !self.portal_active
+            && self.boss.is_none()
+            && self.blocks.iter().all(|block| !block.active || block.block_type == BlockType::Undestroyable)
+        {
             self.next_level();
         }
     }
 
+    /// Current score multiplier: x1, then +1 for every 5 blocks in the combo (max x5)
+    pub fn combo_multiplier(&self) -> u32 {
+        1 + (self.combo / 5).min(4)
+    }
+
+    /// Score a destroyed block, growing the combo and showing a popup at the block
+    fn award_block(&mut self, x: f32, y: f32, player: usize) {
+        self.combo += 1;
+        self.best_combo = self.best_combo.max(self.combo);
+
+        let multiplier = self.combo_multiplier();
+        let base = if self.bonus_stage.is_some() { self.portal.block_points } else { 10 };
+        let points = self.modifiers.scale_points(base * multiplier);
+        self.score += points;
+        self.player_scores[player] += points;
+        self.score_popups.push(ScorePopup::new(x, y, points, multiplier));
+    }
+
+    /// Take away a life and send the penguin after the heart
+    fn lose_life(&mut self, play_sound: &mut dyn FnMut(SoundEffect)) {
+        if self.lives == 0 {
+            return;
+        }
+
+        // Time attack: lost balls cost time, not lives
+        if let Some(ref mut time_attack) = self.time_attack {
+            time_attack.add_penalty();
+            self.stats.lives_lost += 1;
+            self.combo = 0;
+            play_sound(SoundEffect::Oh);
+            return;
+        }
+
+        self.lives -= 1;
+        self.lost_life_this_level = true; // Mark that a life was lost this level
+        self.stats.lives_lost += 1;
+        self.combo = 0;
+        
+        // Scoring: -20 points for losing life (ensure score doesn't go negative)
+        if self.score >= 20 {
+            self.score -= 20;
+        } else {
+            self.score = 0;
+        }
+        
+        play_sound(SoundEffect::Oh);
+        
+        // Penguin animation instead of heart shatter particles
+        // Calculate position of the lost heart (it was at index self.lives)
+        // Logic: WINDOW_WIDTH - 30 - index * 25
+        // Since we just decremented lives, the lost heart index is the current self.lives value
+        // e.g. had 3 lives (indices 0,1,2). Lost one -> lives=2. Lost heart was at index 2.
+        let heart_x = WINDOW_WIDTH as f32 - 30.0 - (self.lives as f32 * 25.0);
+        let heart_y = 25.0; // Heart center Y position
+        
+        // Store the stolen heart position so it stays visible
+        self.stolen_heart_position = Some((heart_x, heart_y));
+        
+        // Spawn penguin to steal the heart
+        self.penguin = Some(Penguin::new(heart_x, heart_y, self.heart_rescue));
+
+        if self.lives == 0 {
+            self.state = GameState::GameOver;
+            if let Some(ref daily) = self.daily {
+                daily.record(self.score, self.current_level - 1, false);
+            }
+        }
+
+        // Hot-seat: the other player takes over once this frame is done
+        if self.mode == GameMode::HotSeat {
+            self.turn_over = true;
+        }
+    }
+
+    fn handle_boss_event(&mut self, event: BossEvent, play_sound: &mut dyn FnMut(SoundEffect)) {
+        let Some((cx, cy)) = self.boss.as_ref().map(|boss| boss.center()) else {
+            return;
+        };
+
+        match event {
+            BossEvent::None => {}
+            BossEvent::Damaged => {
+                self.score += self.modifiers.scale_points(10);
+                play_sound(SoundEffect::Bounce);
+            }
+            BossEvent::PhaseChanged(_) => {
+                self.score += self.modifiers.scale_points(100);
+                self.boss_projectiles.clear();
+                // Enrage burst
+                let mut rng = rng::game_rng();
+                for _ in 0..60 {
+                    let angle = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
+                    let speed = rng.gen::<f32>() * 8.0 + 3.0;
+                    self.particles.push(Particle::new(
+                        cx,
+                        cy,
+                        angle.cos() * speed,
+                        angle.sin() * speed,
+                        Color { r: 255, g: 60, b: 60 },
+                    ));
+                }
+                play_sound(SoundEffect::Explosion);
+            }
+            BossEvent::Defeated => {
+                self.score += self.modifiers.scale_points(2000);
+                self.boss_projectiles.clear();
+                let mut rng = rng::game_rng();
+                for _ in 0..150 {
+                    let angle = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
+                    let speed = rng.gen::<f32>() * 12.0 + 2.0;
+                    let color = BLOCK_COLORS[rng.gen_range(0..BLOCK_COLORS.len())];
+                    self.particles.push(Particle::new(
+                        cx + rng.gen_range(-150.0..150.0),
+                        cy + rng.gen_range(-40.0..40.0),
+                        angle.cos() * speed,
+                        angle.sin() * speed,
+                        color,
+                    ));
+                }
+                play_sound(SoundEffect::Explosion);
+            }
+        }
+    }
+
+    /// Explosions destroy every block within reach. Explosive blocks caught in one
+    /// explode in turn, and each blast sends out a shockwave.
+    fn detonate(
+        &mut self,
+        mut explosions: Vec<(f32, f32, usize)>,
+        destroyed_blocks: &mut Vec<(f32, f32, DestroySource, usize)>,
+        particles_to_spawn: &mut Vec<(f32, f32, Color)>,
+        play_sound: &mut dyn FnMut(SoundEffect),
+    ) {
+        let radius = EXPLOSION_RADIUS;
+
+        while let Some((exp_x, exp_y, player)) = explosions.pop() {
+            for block in &mut self.blocks {
+                if !block.active {
+                    continue;
+                }
+
+                let block_center_x = block.x as f32 + block.width as f32 / 2.0;
+                let block_center_y = block.y as f32 + block.height as f32 / 2.0;
+                let dx = block_center_x - exp_x;
+                let dy = block_center_y - exp_y;
+
+                if dx * dx + dy * dy <= radius * radius {
+                    block.active = false;
+                    destroyed_blocks.push((block_center_x, block_center_y, DestroySource::Explosion, player));
+                    particles_to_spawn.push((block_center_x, block_center_y, block.color));
+
+                    // Chain reaction
+                    if block.block_type == BlockType::Explosive {
+                        explosions.push((block_center_x, block_center_y, player));
+                    }
+                }
+            }
+
+            self.shockwaves.push(Shockwave::new(exp_x, exp_y, radius + SHOCKWAVE_REACH));
+            play_sound(SoundEffect::Explosion);
+        }
+    }
+
+    /// Grow shockwave rings. The front pushes balls and capsules outward and shakes blocks.
+    fn update_shockwaves(&mut self) {
+        for block in &mut self.blocks {
+            block.shake = block.shake.saturating_sub(1);
+        }
+
+        // Blasts alone never speed the ball up to the portal threshold (px per frame)
+        let max_push_speed = self.portal.threshold * 0.9 / 60.0;
+
+        for shockwave in &mut self.shockwaves {
+            shockwave.update();
+
+            for ball in self.balls.iter_mut().filter(|b| b.active && !b.attached_to_paddle) {
+                let center = BALL_SIZE as f32 / 2.0;
+                if let Some((push_x, push_y)) = shockwave.push_at(ball.x + center, ball.y + center) {
+                    let speed_before = ball.vel_x.hypot(ball.vel_y);
+                    ball.vel_x += push_x;
+                    ball.vel_y += push_y;
+
+                    // A push from the far side must not leave the ball crawling sideways
+                    if ball.vel_y.abs() < SHOCKWAVE_MIN_VEL_Y {
+                        ball.vel_y = SHOCKWAVE_MIN_VEL_Y.copysign(ball.vel_y);
+                    }
+
+                    let cap = speed_before.max(max_push_speed);
+                    let speed = ball.vel_x.hypot(ball.vel_y);
+                    if speed > cap {
+                        ball.vel_x *= cap / speed;
+                        ball.vel_y *= cap / speed;
+                    }
+                }
+            }
+
+            for bonus in self.bonuses.iter_mut().filter(|b| b.active) {
+                if let Some((push_x, push_y)) = shockwave.push_at(bonus.x + 20.0, bonus.y + 20.0) {
+                    bonus.push_x += push_x;
+                    bonus.push_y += push_y;
+                }
+            }
+
+            for block in self.blocks.iter_mut().filter(|b| b.active) {
+                let center_x = block.x as f32 + block.width as f32 / 2.0;
+                let center_y = block.y as f32 + block.height as f32 / 2.0;
+                if shockwave.sweeps(center_x, center_y) {
+                    block.shake = BLOCK_SHAKE_FRAMES;
+                }
+            }
+        }
+    }
+
     fn create_particles(&mut self, x: f32, y: f32, color: Color) {
-        let mut rng = rand::thread_rng();
+        let mut rng = rng::game_rng();
         
         // Create 10-15 glass shard particles
         for _ in 0..rng.gen_range(10..16) {
@@ -773,10 +1768,43 @@ impl Game {
             GameState::LevelTransition => GameState::LevelTransition,
             GameState::SplashScreen => GameState::SplashScreen,
             GameState::LevelEditor => GameState::LevelEditor,
+            GameState::PlayerReady => GameState::PlayerReady,
         };
     }
 
-    pub fn toggle_gravity_mode(&mut self) {
-        self.gravity_mode = !self.gravity_mode;
+}
+
+/// Rest an attached ball on the playfield side of a paddle
+fn place_on_paddle(ball: &mut Ball, paddle: &Paddle) {
+    ball.x = paddle.x as f32 + paddle.width as f32 / 2.0 - BALL_SIZE as f32 / 2.0;
+    ball.y = if paddle.is_top() {
+        (paddle.y + PADDLE_HEIGHT) as f32
+    } else {
+        paddle.y as f32 - BALL_SIZE as f32
+    };
+}
+
+/// Reflect a ball off a paddle, adding angle from the hit offset and spin from paddle motion
+fn bounce_off_paddle(ball: &mut Ball, paddle: &mut Paddle) {
+    // Always send the ball back toward the playfield
+    ball.vel_y = if paddle.is_top() { ball.vel_y.abs() } else { -ball.vel_y.abs() };
+    // Add horizontal velocity based on where ball hits paddle
+    let paddle_center = paddle.x + paddle.width / 2;
+    let ball_center = ball.x as i32 + BALL_SIZE / 2;
+    let offset = ball_center - paddle_center;
+    ball.vel_x += offset as f32 * 0.1;
+
+    // Add spin based on paddle velocity and offset
+    // REFINED: Less sensitive, requires minimum velocity
+    let paddle_vel = paddle.vel_x as f32;
+
+    if paddle_vel.abs() > 2.0 {
+        // Only apply spin if moving fast enough
+        ball.spin = (paddle_vel * 0.3) + (offset as f32 * 0.05);
+        // Trigger visual discharge effect
+        paddle.spin_intensity = 1.0;
+    } else {
+        // Minimal spin from just position offset
+        ball.spin = offset as f32 * 0.02;
     }
 }
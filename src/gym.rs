@@ -0,0 +1,275 @@
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpListener;
+use crate::entities::*;
+use crate::game::{Game, GameState};
+use crate::modifiers::ModifierKind;
+use crate::rng;
+
+/// Low-res frames are the playfield scaled down by this factor (1280x720 -> 80x45)
+const FRAME_SCALE: i32 = 16;
+
+/// What an observation contains
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ObservationKind {
+    State, // Structured ball/paddle/block vectors
+    Frame, // Low-res grayscale image
+}
+
+/// Environment settings
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct GymConfig {
+    pub level: usize,
+    pub frame_skip: u32, // Game frames per step; the action repeats on each
+    pub observation: ObservationKind,
+    pub modifiers: Vec<ModifierKind>,
+}
+
+impl Default for GymConfig {
+    fn default() -> Self {
+        GymConfig {
+            level: 1,
+            frame_skip: 4,
+            observation: ObservationKind::State,
+            modifiers: Vec::new(),
+        }
+    }
+}
+
+/// One step's input: hold left/right, optionally press launch/fire
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+#[serde(default)]
+pub struct Action {
+    pub direction: i32, // -1 left, 0 stay, 1 right
+    pub fire: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StateObservation {
+    pub paddle: [f32; 4],         // x, y, width, vel_x
+    pub balls: Vec<[f32; 5]>,     // x, y, vel_x, vel_y, spin
+    pub blocks: Vec<[i32; 4]>,    // x, y, type, health (active blocks only)
+    pub bonuses: Vec<[f32; 3]>,   // x, y, type
+    pub rockets: u32,             // Ammo on the paddle
+    pub lives: u32,
+    pub score: u32,
+    pub level: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct FrameObservation {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>, // Row-major grayscale
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Observation {
+    State(StateObservation),
+    Frame(FrameObservation),
+}
+
+#[derive(Serialize, Debug)]
+pub struct StepResult {
+    pub observation: Observation,
+    pub reward: f32, // Score gained this step (negative when a life costs points)
+    pub done: bool,  // Level cleared, run won or game over
+}
+
+/// Headless stepping API around `Game`
+pub struct GymEnv {
+    pub config: GymConfig,
+    game: Game,
+}
+
+impl GymEnv {
+    pub fn new(config: GymConfig) -> Self {
+        let mut env = GymEnv {
+            config,
+            game: Game::new(),
+        };
+        env.reset(0);
+        env
+    }
+
+    /// Start a new episode. The same seed replays the same episode for the same actions.
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        rng::reseed(seed);
+        self.game = Game::new_level(self.config.level);
+        self.game.set_modifiers(&self.config.modifiers);
+        self.game.state = GameState::Playing;
+        self.observe()
+    }
+
+    pub fn step(&mut self, action: Action) -> StepResult {
+        let score_before = self.game.score as i64;
+
+        for frame in 0..self.config.frame_skip.max(1) {
+            if action.direction != 0 {
+                self.game.move_paddle(0, action.direction.signum());
+            }
+            if action.fire && frame == 0 {
+                self.game.press_action(0, &mut |_| {});
+            }
+            self.game.update(&mut |_| {});
+
+            if self.is_done() {
+                break;
+            }
+        }
+
+        StepResult {
+            observation: self.observe(),
+            reward: (self.game.score as i64 - score_before) as f32,
+            done: self.is_done(),
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.game.state != GameState::Playing
+    }
+
+    pub fn observe(&self) -> Observation {
+        match self.config.observation {
+            ObservationKind::State => Observation::State(self.observe_state()),
+            ObservationKind::Frame => Observation::Frame(self.observe_frame()),
+        }
+    }
+
+    fn observe_state(&self) -> StateObservation {
+        let game = &self.game;
+        StateObservation {
+            paddle: [game.paddle.x as f32, game.paddle.y as f32, game.paddle.width as f32, game.paddle.vel_x as f32],
+            balls: game.balls.iter()
+                .filter(|b| b.active)
+                .map(|b| [b.x, b.y, b.vel_x, b.vel_y, b.spin])
+                .collect(),
+            blocks: game.blocks.iter()
+                .filter(|b| b.active)
+                .map(|b| [b.x, b.y, b.block_type as i32, b.health as i32])
+                .collect(),
+            bonuses: game.bonuses.iter()
+                .filter(|b| b.active)
+                .map(|b| [b.x, b.y, b.bonus_type as i32 as f32])
+                .collect(),
+            rockets: game.paddle.rocket_ammo(),
+            lives: game.lives,
+            score: game.score,
+            level: game.current_level,
+        }
+    }
+
+    /// Software-rasterized playfield: blocks grey by type, capsules light, paddle and balls white
+    fn observe_frame(&self) -> FrameObservation {
+        let width = WINDOW_WIDTH as i32 / FRAME_SCALE;
+        let height = WINDOW_HEIGHT as i32 / FRAME_SCALE;
+        let mut pixels = vec![0u8; (width * height) as usize];
+
+        let mut fill = |x: i32, y: i32, w: i32, h: i32, value: u8| {
+            let x0 = (x / FRAME_SCALE).clamp(0, width);
+            let y0 = (y / FRAME_SCALE).clamp(0, height);
+            // Anything on screen covers at least one pixel
+            let x1 = ((x + w + FRAME_SCALE - 1) / FRAME_SCALE).clamp(x0 + 1, width);
+            let y1 = ((y + h + FRAME_SCALE - 1) / FRAME_SCALE).clamp(y0 + 1, height);
+            for py in y0..y1 {
+                for px in x0..x1 {
+                    pixels[(py * width + px) as usize] = value;
+                }
+            }
+        };
+
+        for block in self.game.blocks.iter().filter(|b| b.active) {
+            let value = match block.block_type {
+                BlockType::Normal => 100,
+                BlockType::Ice => 140,
+                BlockType::Explosive => 170,
+                BlockType::Undestroyable => 60,
+            };
+            fill(block.x, block.y, block.width, block.height, value);
+        }
+        for bonus in self.game.bonuses.iter().filter(|b| b.active) {
+            let rect = bonus.rect();
+            fill(rect.x(), rect.y(), rect.width() as i32, rect.height() as i32, 200);
+        }
+        let paddle = &self.game.paddle;
+        fill(paddle.x, paddle.y, paddle.width, PADDLE_HEIGHT, 255);
+        for ball in self.game.balls.iter().filter(|b| b.active) {
+            fill(ball.x as i32, ball.y as i32, BALL_SIZE, BALL_SIZE, 255);
+        }
+
+        FrameObservation {
+            width: width as u32,
+            height: height as u32,
+            pixels,
+        }
+    }
+}
+
+/// One JSON request per line
+#[derive(Deserialize, Debug)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+enum Request {
+    Configure(GymConfig),
+    Reset {
+        #[serde(default)]
+        seed: u64,
+    },
+    Step {
+        #[serde(default)]
+        action: Action,
+    },
+    Close,
+}
+
+/// Serve the JSON line protocol until `close` or end of input
+fn serve(reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+    let mut env = GymEnv::new(GymConfig::default());
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(Request::Configure(config)) => {
+                env = GymEnv::new(config);
+                serde_json::json!({ "ok": true })
+            }
+            Ok(Request::Reset { seed }) => serde_json::json!({ "observation": env.reset(seed) }),
+            Ok(Request::Step { action }) => serde_json::json!(env.step(action)),
+            Ok(Request::Close) => break,
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        };
+
+        writeln!(writer, "{}", response)?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// `--gym`: protocol over stdin/stdout
+pub fn run_stdio() -> io::Result<()> {
+    let stdin = io::stdin();
+    serve(stdin.lock(), io::stdout().lock())
+}
+
+/// `--gym-port <port>`: protocol over a local TCP socket, one client at a time
+pub fn run_socket(port: u16) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    eprintln!("Gym listening on 127.0.0.1:{}", port);
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let reader = BufReader::new(stream.try_clone()?);
+        if let Err(e) = serve(reader, stream) {
+            eprintln!("Gym client error: {}", e);
+        }
+    }
+
+    Ok(())
+}
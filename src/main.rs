@@ -5,6 +5,23 @@ mod audio;
 mod menu;
 mod editor;
 mod settings;
+mod boss;
+mod stats;
+mod time_attack;
+mod daily;
+mod modifiers;
+mod ai;
+mod attract;
+mod rng;
+mod gym;
+mod effects;
+mod bonus_stage;
+mod campaign;
+mod packs;
+mod validator;
+mod cli;
+mod replay;
+mod preview;
 
 use sdl2::event::Event;
 use sdl2::keyboard::Keycode;
@@ -13,17 +30,68 @@ use sdl2::image::{LoadTexture, LoadSurface, InitFlag};
 use std::time::Duration;
 
 use crate::entities::{WINDOW_WIDTH, WINDOW_HEIGHT};
-use crate::game::{Game, GameState};
+use crate::game::{Game, GameState, GameMode};
 use crate::rendering::{render_game, render_editor};
 use crate::audio::AudioManager;
 use crate::menu::{Menu, MenuState, MenuAction, handle_menu_click};
 use crate::editor::LevelEditor;
 use crate::settings::Settings;
+use crate::ai::AiController;
+use crate::attract::AttractMode;
+use crate::campaign::LevelBackground;
+use crate::cli::Options;
+use crate::replay::{Input, Playback, Recorder, Replay};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match Options::parse(&args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("arkanoo: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if options.help {
+        print!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    // Level thumbnails and terminal previews
+    if let Some((ref input, ref output)) = options.render_pattern {
+        if let Err(e) = preview::run(input, output) {
+            eprintln!("arkanoo: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Level checker for level authors
+    if let Some(ref path) = options.validate {
+        if !validator::run_cli(path) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Headless agent interface - no window, no audio
+    if options.gym {
+        return Ok(gym::run_stdio()?);
+    }
+    if let Some(port) = options.gym_port {
+        return Ok(gym::run_socket(port)?);
+    }
+
     // Load settings
     let mut settings = Settings::load();
 
+    // Command-line overrides last for this session only and are never saved
+    options.apply(&mut settings);
+    if let Some(seed) = options.seed {
+        rng::reseed(seed);
+    }
+
+    // Play the campaign pack picked last time (assets below resolve through it)
+    packs::select(packs::packs().find(&settings.pack));
 
     // Initialize SDL2
     let sdl_context = sdl2::init()?;
@@ -99,10 +167,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Create game, menu, and editor
     let mut game = Game::new();
-    // Apply gravity mode from settings
-    if settings.gravity_mode {
-        game.toggle_gravity_mode();
-    }
+    // Apply modifiers from settings
+    game.set_modifiers(&settings.modifiers);
 
     let mut menu = Menu::new(WINDOW_WIDTH, WINDOW_HEIGHT);
     
@@ -147,11 +213,60 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     menu.set_music_muted(settings.music_muted);
     menu.set_sfx_muted(settings.sfx_muted);
     menu.set_fullscreen(settings.fullscreen);
-    menu.set_gravity_mode(settings.gravity_mode);
+    menu.set_modifiers(&settings.modifiers);
     menu.set_vsync(settings.vsync);
+    menu.set_autopilot(settings.autopilot);
+    // The autopilot follows the run seed too (a fixed one without --seed)
+    let ai_seed = options.seed.unwrap_or_default();
+    let mut autopilot = settings.autopilot.map(|difficulty| AiController::new(difficulty, 0, ai_seed));
+    menu.set_auto_launch(settings.auto_launch);
+    menu.set_heart_rescue(settings.heart_rescue);
+    menu.set_portal(settings.portal.clone());
+    menu.selected_pack = packs::selected();
     menu.set_resolution(settings.resolution_width, settings.resolution_height);
 
-    // Start playing music
+    // Straight into content from the command line
+    let mut recorder = None;
+    let mut playback = None;
+    if let Some(ref path) = options.replay {
+        let replay = Replay::load(path)?;
+        game = replay.start_game()?;
+        menu.set_auto_launch(replay.auto_launch);
+        menu.set_heart_rescue(replay.heart_rescue);
+        menu.set_portal(replay.portal.clone());
+        playback = Some(Playback::new(replay));
+        autopilot = None;
+        menu.set_game_started(true);
+    } else if options.starts_game() {
+        // A seed is always picked so the run can be recorded
+        let seed = options.seed.unwrap_or_else(rand::random);
+        let mut replay = Replay::new(seed, options.level.unwrap_or(1), options.pattern.clone(), settings.modifiers.clone());
+        replay.auto_launch = menu.auto_launch;
+        replay.heart_rescue = menu.heart_rescue;
+        replay.portal = menu.portal.clone();
+        game = replay.start_game()?;
+        menu.set_game_started(true);
+        if let Some(ref path) = options.record {
+            recorder = Some(Recorder::new(path.clone(), replay));
+        }
+    } else if let Some(ref path) = options.editor {
+        game.state = GameState::LevelEditor;
+        if let Some(path) = path {
+            if let Err(e) = editor.load_pattern_file(path) {
+                eprintln!("{}", e);
+            }
+        }
+    } else if options.skip_splash {
+        game.state = GameState::Paused;
+        menu.state = MenuState::Main;
+    }
+    if game.state == GameState::Paused || game.state == GameState::LevelEditor {
+        sdl_context.mouse().show_cursor(true);
+        canvas.window_mut().set_grab(false);
+    }
+
+    // Start playing music (the first level may bring its own track)
+    audio_manager.set_track(game.level_music());
     audio_manager.play_music();
 
     let mut mouse_down = false;
@@ -165,15 +280,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut frame_times: Vec<std::time::Instant> = Vec::new();
     let mut current_fps;
     
-    // Cache background and track current level
-    let mut current_level = game.current_level;
-    let mut background = texture_creator
-        .load_texture(game.get_background_path())
-        .ok();
+    // Cache background and track the shown level (a bonus stage has its own backdrop)
+    let shown_level = |game: &Game| (game.current_level, game.bonus_stage.is_some());
+    let mut current_level = shown_level(&game);
+    let mut background = match game.background() {
+        LevelBackground::Image(path) => texture_creator.load_texture(packs::resolve(&path)).ok(),
+        LevelBackground::Theme(_) => None,
+    };
     
     // Menu background - always use first level background
     let mut menu_background = texture_creator
-        .load_texture("assets/background1.png")
+        .load_texture(packs::resolve("assets/background1.png"))
         .ok();
     
     // Editor background
@@ -186,19 +303,34 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let target_frame_time = Duration::from_micros(1_000_000 / 60);
 
+    // Title screen demo - plays its own game on its own generator, the player's paused game is untouched
+    let mut attract = AttractMode::new();
+
     'running: loop {
         let frame_start = std::time::Instant::now();
 
-        // Reload background only if level changed
-        if game.current_level != current_level {
-            current_level = game.current_level;
-            background = texture_creator
-                .load_texture(game.get_background_path())
-                .ok();
+        // Reload background only if the shown level changed (the demo has its own)
+        let shown_game = attract.game().unwrap_or(&game);
+        if shown_level(shown_game) != current_level {
+            current_level = shown_level(shown_game);
+            background = match shown_game.background() {
+                LevelBackground::Image(path) => texture_creator.load_texture(packs::resolve(&path)).ok(),
+                LevelBackground::Theme(_) => None,
+            };
+            audio_manager.set_track(shown_game.level_music());
         }
 
         // Handle events
         for event in event_pump.poll_iter() {
+            // Any input resets the idle timer; input that stops the demo goes no further
+            let is_input = matches!(
+                event,
+                Event::KeyDown { .. } | Event::MouseButtonDown { .. } | Event::MouseMotion { .. } | Event::MouseWheel { .. }
+            );
+            if is_input && attract.on_input() {
+                continue;
+            }
+
             match event {
                 Event::Quit { .. } => {
                     // Save settings on exit
@@ -209,7 +341,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     settings.sfx_volume = audio_manager.get_sfx_volume();
                     settings.music_muted = audio_manager.is_music_muted();
                     settings.sfx_muted = audio_manager.is_sfx_muted();
-                    settings.gravity_mode = game.gravity_mode;
+                    settings.modifiers = menu.modifiers.clone();
                     
                     if let Err(e) = settings.save() {
                         eprintln!("Failed to save settings: {}", e);
@@ -233,6 +365,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     } else if game.state != GameState::GameOver && game.state != GameState::Victory {
                         game.toggle_pause();
                         menu.state = MenuState::Main;
+                        if game.state == GameState::Playing && !menu.game_started {
+                            // Leaving the menu starts a game that was not started yet
+                            menu.set_game_started(true);
+                            game.start_run();
+                        }
                         
                         // Show/Hide cursor based on pause state
                         if game.state == GameState::Paused {
@@ -269,7 +406,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 
                 Event::KeyDown { keycode: Some(Keycode::R), .. } => {
                     if game.state == GameState::Paused || game.state == GameState::GameOver || game.state == GameState::Victory {
-                        game.reset();
+                        end_session(&mut recorder, &mut playback);
+                        game.reset(&menu.modifiers);
                         menu.state = MenuState::Main;
                         // Ensure cursor is hidden/grabbed when restarting
                         sdl_context.mouse().show_cursor(false);
@@ -285,10 +423,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         sdl_context.mouse().show_cursor(true);
                         canvas.window_mut().set_grab(false);
                     } else if game.state == GameState::Victory {
-                        game.start_next_level(); // Starts level 10 (Infinite Mode)
+                        if matches!(game.mode, GameMode::TimeAttack | GameMode::Daily) {
+                            // Straight into another run
+                            end_session(&mut recorder, &mut playback);
+                            game.reset(&menu.modifiers);
+                            game.state = GameState::Playing;
+                        } else {
+                            game.start_next_level(); // Starts level 11 (Infinite Mode)
+                        }
+                    } else if game.state == GameState::PlayerReady {
+                        game.state = GameState::Playing;
                     } else if game.state == GameState::LevelTransition {
-                        game.start_next_level();
-                        // Music continues playing, no change needed
+                        if game.results.is_revealed() {
+                            game.start_next_level();
+                            // Music continues playing, no change needed
+                        } else {
+                            // First press skips the count-up
+                            game.results.reveal_all();
+                        }
                     }
                 }
 
@@ -302,15 +454,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         settings.sfx_volume = audio_manager.get_sfx_volume();
                         settings.music_muted = audio_manager.is_music_muted();
                         settings.sfx_muted = audio_manager.is_sfx_muted();
-                        settings.gravity_mode = game.gravity_mode;
+                        settings.modifiers = menu.modifiers.clone();
                         
                         if let Err(e) = settings.save() {
                             eprintln!("Failed to save settings: {}", e);
                         }
 
                         break 'running;
-                    } else if game.state == GameState::Playing {
-                        // Cheat: Skip to next level
+                    } else if game.state == GameState::Playing && recorder.is_none() && playback.is_none() {
+                        // Cheat: Skip to next level (not while recording or replaying, it is no input)
                         if game.blocks.iter().any(|b| b.active) {
                             // Clear all blocks to trigger level transition
                             for block in &mut game.blocks {
@@ -320,26 +472,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
 
-                Event::KeyDown { keycode: Some(Keycode::Space), .. } => {
-                    if game.state == GameState::Playing {
-                        // Check if any balls are attached to paddle
-                        let has_attached_balls = game.balls.iter().any(|b| b.attached_to_paddle);
-                        
-                        if has_attached_balls {
-                            // Launch attached balls
-                            game.launch_balls();
-                        } else {
-                            // Fire rocket if no balls are attached
-                            let mut sound_to_play = None;
-                            game.fire_rocket(&mut |effect| sound_to_play = Some(effect));
-                            if let Some(effect) = sound_to_play {
-                                match effect {
-                                    crate::game::SoundEffect::Bounce => audio_manager.play_bounce(),
-                                    crate::game::SoundEffect::Oh => audio_manager.play_oh(),
-                                    crate::game::SoundEffect::Load => audio_manager.play_load(),
-                                    crate::game::SoundEffect::BreakingGlass => audio_manager.play_breaking_glass(),
-                                    crate::game::SoundEffect::Explosion => audio_manager.play_explosion(),
-                                }
+                // Space launches/fires for player 1; W does the same for player 2 (co-op, versus)
+                Event::KeyDown { keycode: Some(key @ (Keycode::Space | Keycode::W)), .. }
+                    if key == Keycode::Space || (game.state == GameState::Playing && game.paddle2.is_some()) =>
+                {
+                    if game.state == GameState::Playing && playback.is_none() {
+                        let player = if key == Keycode::W { 1 } else { 0 };
+
+                        // Launch attached balls, or fire a rocket if none are attached
+                        let mut sound_to_play = None;
+                        replay::send(&mut game, recorder.as_mut(), Input::Action { player }, &mut |effect| sound_to_play = Some(effect));
+                        if let Some(effect) = sound_to_play {
+                            match effect {
+                                crate::game::SoundEffect::Bounce => audio_manager.play_bounce(),
+                                crate::game::SoundEffect::Oh => audio_manager.play_oh(),
+                                crate::game::SoundEffect::Load => audio_manager.play_load(),
+                                crate::game::SoundEffect::BreakingGlass => audio_manager.play_breaking_glass(),
+                                crate::game::SoundEffect::Explosion => audio_manager.play_explosion(),
                             }
                         }
                     }
@@ -400,26 +549,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             Keycode::T => {
                                 // Start Quick Game
                                 if !editor.blocks.is_empty() {
-                                    game.state = GameState::Playing;
                                     // Reset game state but keep blocks
-                                    game.paddle = crate::entities::Paddle::new();
-                                    game.balls = vec![crate::entities::Ball::new(
-                                        game.paddle.x as f32 + game.paddle.width as f32 / 2.0 - crate::entities::BALL_SIZE as f32 / 2.0,
-                                        game.paddle.y as f32 - crate::entities::BALL_SIZE as f32,
-                                    )];
-                                    game.blocks = editor.blocks.clone();
-                                    game.bonuses.clear();
-                                    game.particles.clear();
-                                    game.rockets.clear();
-                                    game.penguin = None;
-                                    game.stolen_heart_position = None;
-                                    game.score = 0;
-                                    game.lives = 3;
-                                    game.lost_life_this_level = false;
-                                    game.portal_active = false;
-                                    game.portal_completion_timer = 0;
-                                    game.max_speed = 0.0;
-                                    game.is_test_mode = true;
+                                    end_session(&mut recorder, &mut playback);
+                                    game.start_test_level(editor.blocks.clone());
                                     
                                     // Hide cursor
                                     sdl_context.mouse().show_cursor(false);
@@ -459,6 +591,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     }
                                 }
                             }
+                            Keycode::U => {
+                                // Upgrade ASCII patterns to the v2 format
+                                editor.cancel_clear();
+                                editor.upgrade_patterns();
+                            }
+                            Keycode::G => {
+                                // Cycle grid size presets
+                                editor.cancel_clear();
+                                editor.next_layout();
+                            }
                             Keycode::N => {
                                 editor.pattern_name_editing = true;
                                 editor.cancel_clear(); // Cancel any pending clear
@@ -566,10 +708,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 audio_manager.set_sfx_volume(new_sfx_volume);
                             }
                         }
-                    } else if game.state == GameState::Playing {
+                    } else if game.state == GameState::Playing && playback.is_none() {
                         // Mouse control for paddle - center paddle on mouse X position
-                        let paddle_center_x = adj_x - (game.paddle.width / 2);
-                        game.paddle.set_x(paddle_center_x);
+                        // (with two paddles the mouse belongs to player 2).
+                        // A resting ball is aimed at the cursor instead, unless another is in play.
+                        let player = if game.paddle2.is_some() { 1 } else { 0 };
+                        let input = if game.is_aiming(player) {
+                            Input::AimAt { player, x: adj_x, y: adj_y }
+                        } else {
+                            Input::MoveTo { player, x: adj_x }
+                        };
+                        replay::send(&mut game, recorder.as_mut(), input, &mut |_| {});
                     }
                 }
 
@@ -601,26 +750,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         } else if editor.test_button.is_clicked(adj_x, adj_y) {
                             // Start Quick Game
                             if !editor.blocks.is_empty() {
-                                game.state = GameState::Playing;
                                 // Reset game state but keep blocks
-                                game.paddle = crate::entities::Paddle::new();
-                                game.balls = vec![crate::entities::Ball::new(
-                                    game.paddle.x as f32 + game.paddle.width as f32 / 2.0 - crate::entities::BALL_SIZE as f32 / 2.0,
-                                    game.paddle.y as f32 - crate::entities::BALL_SIZE as f32,
-                                )];
-                                game.blocks = editor.blocks.clone();
-                                game.bonuses.clear();
-                                game.particles.clear();
-                                game.rockets.clear();
-                                game.penguin = None;
-                                game.stolen_heart_position = None;
-                                game.score = 0;
-                                game.lives = 3;
-                                game.lost_life_this_level = false;
-                                game.portal_active = false;
-                                game.portal_completion_timer = 0;
-                                game.max_speed = 0.0;
-                                game.is_test_mode = true;
+                                end_session(&mut recorder, &mut playback);
+                                game.start_test_level(editor.blocks.clone());
                                 
                                 // Hide cursor
                                 sdl_context.mouse().show_cursor(false);
@@ -697,6 +829,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             MenuAction::NewGame => {
                                 // First time starting - mark game as started
                                 menu.set_game_started(true);
+                                game.start_run();
                                 game.state = GameState::Playing;
                                 // Hide cursor when starting
                                 sdl_context.mouse().show_cursor(false);
@@ -709,7 +842,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 canvas.window_mut().set_grab(true);
                             }
                             MenuAction::Restart => {
-                                game.reset();
+                                end_session(&mut recorder, &mut playback);
+                                game.reset(&menu.modifiers);
                                 menu.set_game_started(true);
                                 // Music continues playing, no change needed
                                 // Hide cursor when restarting
@@ -727,8 +861,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 settings.sfx_volume = audio_manager.get_sfx_volume();
                                 settings.music_muted = audio_manager.is_music_muted();
                                 settings.sfx_muted = audio_manager.is_sfx_muted();
-                                settings.gravity_mode = game.gravity_mode;
+                                settings.modifiers = menu.modifiers.clone();
                                 settings.vsync = menu.vsync_enabled;
+                                settings.autopilot = menu.autopilot;
+                                settings.auto_launch = menu.auto_launch;
+                                settings.heart_rescue = menu.heart_rescue;
+                                settings.portal = menu.portal.clone();
                                 
                                 if let Err(e) = settings.save() {
                                     eprintln!("Failed to save settings: {}", e);
@@ -751,8 +889,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 settings.sfx_volume = audio_manager.get_sfx_volume();
                                 settings.music_muted = audio_manager.is_music_muted();
                                 settings.sfx_muted = audio_manager.is_sfx_muted();
-                                settings.gravity_mode = game.gravity_mode;
+                                settings.modifiers = menu.modifiers.clone();
                                 settings.vsync = menu.vsync_enabled;
+                                settings.autopilot = menu.autopilot;
+                                settings.auto_launch = menu.auto_launch;
+                                settings.heart_rescue = menu.heart_rescue;
+                                settings.portal = menu.portal.clone();
                                 
                                 if let Err(e) = settings.save() {
                                     eprintln!("Failed to save settings: {}", e);
@@ -782,9 +924,84 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 settings.vsync = new_vsync;
                                 // Note: VSync is set at canvas creation, so change takes effect on restart
                             }
-                            MenuAction::ToggleGravity => {
-                                game.toggle_gravity_mode();
-                                menu.set_gravity_mode(game.gravity_mode);
+                            MenuAction::CycleAutopilot => {
+                                menu.set_autopilot(menu.next_autopilot());
+                                settings.autopilot = menu.autopilot;
+                                autopilot = menu.autopilot.map(|difficulty| AiController::new(difficulty, 0, ai_seed));
+                            }
+                            MenuAction::ToggleAutoLaunch => {
+                                menu.set_auto_launch(!menu.auto_launch);
+                                settings.auto_launch = menu.auto_launch;
+                            }
+                            MenuAction::ToggleHeartRescue => {
+                                menu.set_heart_rescue(!menu.heart_rescue);
+                                settings.heart_rescue = menu.heart_rescue;
+                            }
+                            MenuAction::ToggleBonusStage => {
+                                let mut portal = menu.portal.clone();
+                                portal.bonus_stage = !portal.bonus_stage;
+                                menu.set_portal(portal);
+                                settings.portal = menu.portal.clone();
+                            }
+                            MenuAction::OpenModifiers => {
+                                menu.state = MenuState::Modifiers;
+                            }
+                            MenuAction::CloseModifiers => {
+                                menu.state = MenuState::Main;
+                                settings.modifiers = menu.modifiers.clone();
+                                if let Err(e) = settings.save() {
+                                    eprintln!("Failed to save settings: {}", e);
+                                }
+                            }
+                            MenuAction::ToggleModifier(kind) => {
+                                menu.toggle_modifier(kind);
+                                // A run in progress keeps its modifiers until restarted
+                                if !menu.game_started {
+                                    game.set_modifiers(&menu.modifiers);
+                                }
+                            }
+                            MenuAction::OpenModes => {
+                                menu.state = MenuState::Modes;
+                            }
+                            MenuAction::CloseModes => {
+                                menu.state = MenuState::Main;
+                            }
+                            MenuAction::OpenCampaigns => {
+                                menu.state = MenuState::Campaigns;
+                            }
+                            MenuAction::CloseCampaigns => {
+                                menu.state = MenuState::Main;
+                            }
+                            MenuAction::SelectPack(index) => {
+                                if index != packs::selected() {
+                                    packs::select(index);
+                                    menu.selected_pack = index;
+                                    settings.pack = packs::current().id.clone();
+                                    if let Err(e) = settings.save() {
+                                        eprintln!("Failed to save settings: {}", e);
+                                    }
+
+                                    // Pack sounds and music, then a fresh run of the new campaign
+                                    audio_manager.load_assets();
+                                    menu_background = texture_creator
+                                        .load_texture(packs::resolve("assets/background1.png"))
+                                        .ok();
+                                    end_session(&mut recorder, &mut playback);
+                                    game = Game::with_mode(game.mode, &menu.modifiers);
+                                    menu.set_game_started(false);
+                                    current_level = (0, false); // Reload the level background
+                                }
+                            }
+                            MenuAction::StartMode(mode) => {
+                                end_session(&mut recorder, &mut playback);
+                                game = Game::with_mode(mode, &menu.modifiers);
+                                game.start_run();
+                                game.state = GameState::Playing;
+                                menu.state = MenuState::Main;
+                                menu.set_game_started(true);
+                                // Hide cursor when starting
+                                sdl_context.mouse().show_cursor(false);
+                                canvas.window_mut().set_grab(true);
                             }
                             MenuAction::EnterLevelEditor => {
                                 game.state = GameState::LevelEditor;
@@ -830,13 +1047,34 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             }
                             MenuAction::None => {}
                         }
+                    } else if game.state == GameState::PlayerReady {
+                        // Click to start the next player's turn
+                        game.state = GameState::Playing;
                     } else if game.state == GameState::LevelTransition {
-                        // Click to start next level
-                        game.start_next_level();
-                        // Music continues playing
+                        if game.results.is_revealed() {
+                            // Click to start next level
+                            game.start_next_level();
+                            // Music continues playing
+                        } else {
+                            // First click skips the count-up
+                            game.results.reveal_all();
+                        }
                     } else if game.state == GameState::Victory {
-                        // Click to start infinite mode (level 10)
-                        game.start_next_level();
+                        if matches!(game.mode, GameMode::TimeAttack | GameMode::Daily) {
+                            // Click to try again
+                            end_session(&mut recorder, &mut playback);
+                            game.reset(&menu.modifiers);
+                            game.state = GameState::Playing;
+                        } else {
+                            // Click to start infinite mode (level 11)
+                            game.start_next_level();
+                        }
+                    } else if game.state == GameState::Playing && playback.is_none() {
+                        // Click launches the ball resting on the mouse player's paddle
+                        let player = if game.paddle2.is_some() { 1 } else { 0 };
+                        if game.has_resting_ball(player) {
+                            replay::send(&mut game, recorder.as_mut(), Input::Launch { player }, &mut |_| {});
+                        }
                     }
 
                 }
@@ -869,13 +1107,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         // Handle continuous input (arrow keys)
-        if game.state == GameState::Playing {
+        if game.state == GameState::Playing && playback.is_none() {
             let keyboard_state = event_pump.keyboard_state();
+            let mut held = Vec::new();
             if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::Left) {
-                game.paddle.move_left();
+                held.push(Input::Move { player: 0, direction: -1 });
             }
             if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::Right) {
-                game.paddle.move_right();
+                held.push(Input::Move { player: 0, direction: 1 });
+            }
+
+            // Co-op and versus: player 2 uses A/D
+            if game.paddle2.is_some() {
+                if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::A) {
+                    held.push(Input::Move { player: 1, direction: -1 });
+                }
+                if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::D) {
+                    held.push(Input::Move { player: 1, direction: 1 });
+                }
+            }
+            for input in held {
+                replay::send(&mut game, recorder.as_mut(), input, &mut |_| {});
             }
         }
 
@@ -909,7 +1161,47 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // Update game
         let mut sound_to_play = None;
+
+        // Autopilot steers Player 1's paddle (the player can still take over with mouse or keys)
+        if let Some(ref mut ai) = autopilot {
+            if game.state == GameState::Playing && !game.is_test_mode {
+                let input = ai.tick(&game);
+                replay::send(&mut game, recorder.as_mut(), Input::Steer { player: ai.player, x: input.target_x }, &mut |_| {});
+                if input.action {
+                    replay::send(&mut game, recorder.as_mut(), Input::Action { player: ai.player }, &mut |effect| sound_to_play = Some(effect));
+                }
+            }
+        }
+
+        // Applied every frame so new games and hot-seat turns pick up the settings.
+        // A recorded or replayed run keeps the ones in its header.
+        if recorder.is_none() && playback.is_none() {
+            game.auto_launch = menu.auto_launch;
+            game.heart_rescue = menu.heart_rescue;
+            game.portal = menu.portal.clone();
+        }
+
+        // Replays feed their inputs where the player's would have gone; play frames
+        // only count while the game runs, so pauses do not shift them
+        if game.state == GameState::Playing {
+            if let Some(ref mut playback) = playback {
+                playback.tick(&mut game, &mut |effect| sound_to_play = Some(effect));
+            }
+            if let Some(ref mut recorder) = recorder {
+                recorder.tick();
+            }
+        }
         game.update(&mut |effect| sound_to_play = Some(effect));
+
+        // A replay carries on through the results screens by itself
+        if let Some(ref finished) = playback {
+            if finished.is_finished() || game.state == GameState::GameOver {
+                playback = None;
+            } else if game.state == GameState::LevelTransition || game.state == GameState::Victory {
+                game.results.reveal_all();
+                game.start_next_level();
+            }
+        }
         
         // Check for test mode completion
         if game.is_test_mode && (game.state == GameState::GameOver || game.state == GameState::Victory || game.state == GameState::LevelTransition) {
@@ -937,6 +1229,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             editor.update();
         }
 
+        // Attract mode waits for the main menu to sit idle
+        attract.update(game.state == GameState::Paused && menu.state == MenuState::Main);
+
         // Update audio (for song transitions)
         audio_manager.update();
         
@@ -950,7 +1245,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         if game.state == GameState::LevelEditor {
             render_editor(&mut canvas, &editor, &font, editor_background.as_mut(), &texture_cache);
         } else {
-            render_game(&mut canvas, &game, &menu, background.as_mut(), menu_background.as_mut(), heart_texture.as_ref(), splash_texture.as_mut(), &font, current_fps, splash_timer, &mut texture_cache);
+            let shown_game = attract.game().unwrap_or(&game);
+            render_game(&mut canvas, shown_game, &menu, background.as_mut(), menu_background.as_mut(), heart_texture.as_ref(), splash_texture.as_mut(), &font, current_fps, splash_timer, attract.is_running(), &mut texture_cache);
         }
 
         // Target 60 FPS
@@ -960,6 +1256,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    if let Some(recorder) = recorder {
+        recorder.save();
+    }
+
     audio_manager.stop_music();
     Ok(())
 }
+
+/// The command-line run is over (a new game started): save its recording, stop its replay
+fn end_session(recorder: &mut Option<Recorder>, playback: &mut Option<Playback>) {
+    if let Some(recorder) = recorder.take() {
+        recorder.save();
+    }
+    *playback = None;
+}
@@ -1,10 +1,17 @@
 use sdl2::rect::Rect;
+use crate::game::GameMode;
+use crate::modifiers::*;
+use crate::ai::AiDifficulty;
+use crate::bonus_stage::PortalConfig;
+use crate::packs;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum MenuState {
     Main,
     Settings,
-
+    Modes,
+    Modifiers,
+    Campaigns,
 }
 
 #[derive(Clone)]
@@ -77,6 +84,8 @@ pub struct Menu {
     pub restart_button: Button,
     pub settings_button: Button,
     pub level_editor_button: Button,
+    pub modes_button: Button,
+    pub campaigns_button: Button,
     pub back_button: Button,
     pub quit_button: Button,
     pub music_toggle_button: Button,
@@ -85,10 +94,31 @@ pub struct Menu {
 
     pub fullscreen_button: Button,
     pub vsync_button: Button,
-    pub gravity_mode_button: Button,
+    pub autopilot_button: Button,
+    pub auto_launch_button: Button,
+    pub heart_rescue_button: Button,
+    pub bonus_stage_button: Button,
+    pub modifiers_button: Button,
     pub music_slider: VolumeSlider,
     pub sfx_slider: VolumeSlider,
 
+    // Game modes menu
+    pub campaign_button: Button,
+    pub time_attack_button: Button,
+    pub daily_button: Button,
+    pub hot_seat_button: Button,
+    pub co_op_button: Button,
+    pub versus_button: Button,
+    pub modes_back_button: Button,
+
+    // Modifiers menu - one toggle per ModifierKind::ALL entry
+    pub modifier_buttons: Vec<Button>,
+    pub modifiers_back_button: Button,
+
+    // Campaigns menu - built-in campaign first, then the installed packs
+    pub pack_buttons: Vec<Button>,
+    pub campaigns_back_button: Button,
+
     // Resolution selection - list of clickable resolution buttons
     pub resolution_label: String,
     pub resolution_buttons: Vec<Button>,
@@ -105,7 +135,12 @@ pub struct Menu {
     pub sfx_muted: bool,
     pub is_fullscreen: bool,
     pub vsync_enabled: bool,
-    pub gravity_mode: bool,
+    pub autopilot: Option<AiDifficulty>,
+    pub auto_launch: bool,
+    pub heart_rescue: bool,
+    pub portal: PortalConfig,
+    pub modifiers: Vec<ModifierKind>, // Selection for the next game
+    pub selected_pack: usize,
     pub game_started: bool, // Track if game has been started (for New Game vs Resume)
 }
 
@@ -126,12 +161,46 @@ impl Menu {
         Menu {
             state: MenuState::Main,
             // Main menu - use "New Game" initially, will change to "Resume" once game starts
-            resume_button: Button::new(center_x, center_y - 125, 200, 40, "New Game"),
-            restart_button: Button::new(center_x, center_y - 75, 200, 40, "Restart"),
-            gravity_mode_button: Button::new(center_x, center_y - 25, 200, 40, "Gravity Mode"),
-            level_editor_button: Button::new(center_x, center_y + 25, 200, 40, "Level Editor"),
-            settings_button: Button::new(center_x, center_y + 75, 200, 40, "Settings"),
-            quit_button: Button::new(center_x, center_y + 125, 200, 40, "Quit"),
+            resume_button: Button::new(center_x, center_y - 175, 200, 40, "New Game"),
+            restart_button: Button::new(center_x, center_y - 125, 200, 40, "Restart"),
+            modes_button: Button::new(center_x, center_y - 75, 200, 40, "Game Modes"),
+            campaigns_button: Button::new(center_x, center_y - 25, 200, 40, "Campaigns"),
+            modifiers_button: Button::new(center_x, center_y + 25, 200, 40, "Modifiers"),
+            level_editor_button: Button::new(center_x, center_y + 75, 200, 40, "Level Editor"),
+            settings_button: Button::new(center_x, center_y + 125, 200, 40, "Settings"),
+            quit_button: Button::new(center_x, center_y + 175, 200, 40, "Quit"),
+
+            // Game modes menu
+            campaign_button: Button::new(center_x, center_y - 240, 200, 40, "Campaign"),
+            time_attack_button: Button::new(center_x, center_y - 190, 200, 40, "Time Attack"),
+            daily_button: Button::new(center_x, center_y - 110, 200, 40, "Daily Challenge"),
+            hot_seat_button: Button::new(center_x, center_y - 30, 200, 40, "2 Players"),
+            co_op_button: Button::new(center_x, center_y + 50, 200, 40, "Co-op"),
+            versus_button: Button::new(center_x, center_y + 130, 200, 40, "Versus"),
+            modes_back_button: Button::new(center_x, center_y + 220, 200, 40, "Back"),
+
+            // Modifiers menu
+            modifier_buttons: ModifierKind::ALL
+                .iter()
+                .enumerate()
+                .map(|(i, _)| Button::new(center_x - 60, center_y - 160 + i as i32 * 45, 320, 38, ""))
+                .collect(),
+            modifiers_back_button: Button::new(center_x, center_y + 210, 200, 40, "Back"),
+
+            // Campaigns menu
+            pack_buttons: packs::packs()
+                .packs
+                .iter()
+                .enumerate()
+                .map(|(i, pack)| {
+                    let mut label = format!("{} ({} levels)", pack.name, pack.campaign.last_level());
+                    if !pack.author.is_empty() {
+                        label = format!("{} by {}", label, pack.author);
+                    }
+                    Button::new(center_x - 110, center_y - 160 + i as i32 * 45, 420, 38, &label)
+                })
+                .collect(),
+            campaigns_back_button: Button::new(center_x, center_y + 210, 200, 40, "Back"),
             
             // Settings menu - improved layout with proper spacing
             // Row 1: Music toggle and slider (y offset: -140 and -100)
@@ -147,6 +216,18 @@ impl Menu {
             
             // Row 4: VSync toggle (y offset: +90)
             vsync_button: Button::new(center_x, center_y + 90, 200, 40, "VSync: ON"),
+
+            // Beside fullscreen: autopilot difficulty cycle
+            autopilot_button: Button::new(center_x + 220, center_y + 40, 200, 40, "Autopilot: OFF"),
+
+            // Beside VSync: auto-launch toggle
+            auto_launch_button: Button::new(center_x + 220, center_y + 90, 200, 40, "Auto-launch: ON"),
+
+            // Beside SFX: penguin heart rescue toggle
+            heart_rescue_button: Button::new(center_x + 220, center_y - 50, 200, 40, "Heart Rescue: OFF"),
+
+            // Beside music: portal warps to a bonus stage
+            bonus_stage_button: Button::new(center_x + 220, center_y - 140, 200, 40, "Bonus Stage: OFF"),
             
             // Row 5: Resolution selection - list of resolution buttons
             // Create buttons for each resolution
@@ -188,7 +269,12 @@ impl Menu {
             sfx_muted: false,
             is_fullscreen: false,
             vsync_enabled: true,
-            gravity_mode: false,
+            autopilot: None,
+            auto_launch: true,
+            heart_rescue: false,
+            portal: PortalConfig::default(),
+            modifiers: Vec::new(),
+            selected_pack: 0,
             game_started: false, // Initially false - shows "New Game"
         }
     }
@@ -198,7 +284,9 @@ impl Menu {
             MenuState::Main => {
                 self.resume_button.update_hover(mouse_x, mouse_y);
                 self.restart_button.update_hover(mouse_x, mouse_y);
-                self.gravity_mode_button.update_hover(mouse_x, mouse_y);
+                self.modes_button.update_hover(mouse_x, mouse_y);
+                self.campaigns_button.update_hover(mouse_x, mouse_y);
+                self.modifiers_button.update_hover(mouse_x, mouse_y);
                 self.level_editor_button.update_hover(mouse_x, mouse_y);
                 self.settings_button.update_hover(mouse_x, mouse_y);
                 self.quit_button.update_hover(mouse_x, mouse_y);
@@ -209,6 +297,10 @@ impl Menu {
                 self.sfx_toggle_button.update_hover(mouse_x, mouse_y);
                 self.fullscreen_button.update_hover(mouse_x, mouse_y);
                 self.vsync_button.update_hover(mouse_x, mouse_y);
+                self.autopilot_button.update_hover(mouse_x, mouse_y);
+                self.auto_launch_button.update_hover(mouse_x, mouse_y);
+                self.heart_rescue_button.update_hover(mouse_x, mouse_y);
+                self.bonus_stage_button.update_hover(mouse_x, mouse_y);
                 self.back_button.update_hover(mouse_x, mouse_y);
                 
                 // Resolution list buttons (only when not confirming)
@@ -224,6 +316,27 @@ impl Menu {
                     self.cancel_button.update_hover(mouse_x, mouse_y);
                 }
             }
+            MenuState::Modes => {
+                self.campaign_button.update_hover(mouse_x, mouse_y);
+                self.time_attack_button.update_hover(mouse_x, mouse_y);
+                self.daily_button.update_hover(mouse_x, mouse_y);
+                self.hot_seat_button.update_hover(mouse_x, mouse_y);
+                self.co_op_button.update_hover(mouse_x, mouse_y);
+                self.versus_button.update_hover(mouse_x, mouse_y);
+                self.modes_back_button.update_hover(mouse_x, mouse_y);
+            }
+            MenuState::Modifiers => {
+                for btn in &mut self.modifier_buttons {
+                    btn.update_hover(mouse_x, mouse_y);
+                }
+                self.modifiers_back_button.update_hover(mouse_x, mouse_y);
+            }
+            MenuState::Campaigns => {
+                for btn in &mut self.pack_buttons {
+                    btn.update_hover(mouse_x, mouse_y);
+                }
+                self.campaigns_back_button.update_hover(mouse_x, mouse_y);
+            }
 
         }
     }
@@ -264,9 +377,24 @@ impl Menu {
         };
     }
     
-    pub fn set_gravity_mode(&mut self, gravity_mode: bool) {
-        self.gravity_mode = gravity_mode;
-        // Keep label as "Gravity Mode" - don't change it
+    pub fn set_modifiers(&mut self, modifiers: &[ModifierKind]) {
+        self.modifiers = modifiers.to_vec();
+        for (btn, kind) in self.modifier_buttons.iter_mut().zip(ModifierKind::ALL) {
+            let mutator = kind.mutator();
+            let state = if self.modifiers.contains(&kind) { "ON" } else { "OFF" };
+            btn.label = format!("{}: {} (x{:.2})", mutator.name(), state, mutator.score_multiplier());
+        }
+    }
+
+    /// Flip one modifier in the selection
+    pub fn toggle_modifier(&mut self, kind: ModifierKind) {
+        let mut modifiers = self.modifiers.clone();
+        if let Some(pos) = modifiers.iter().position(|&k| k == kind) {
+            modifiers.remove(pos);
+        } else {
+            modifiers.push(kind);
+        }
+        self.set_modifiers(&modifiers);
     }
     
     pub fn set_vsync(&mut self, enabled: bool) {
@@ -278,6 +406,52 @@ impl Menu {
         };
     }
     
+    pub fn set_autopilot(&mut self, autopilot: Option<AiDifficulty>) {
+        self.autopilot = autopilot;
+        self.autopilot_button.label = match autopilot {
+            Some(difficulty) => format!("Autopilot: {}", difficulty.name()),
+            None => "Autopilot: OFF".to_string(),
+        };
+    }
+
+    pub fn set_auto_launch(&mut self, enabled: bool) {
+        self.auto_launch = enabled;
+        self.auto_launch_button.label = if enabled {
+            "Auto-launch: ON".to_string()
+        } else {
+            "Auto-launch: OFF".to_string()
+        };
+    }
+
+    pub fn set_heart_rescue(&mut self, enabled: bool) {
+        self.heart_rescue = enabled;
+        self.heart_rescue_button.label = if enabled {
+            "Heart Rescue: ON".to_string()
+        } else {
+            "Heart Rescue: OFF".to_string()
+        };
+    }
+
+    pub fn set_portal(&mut self, portal: PortalConfig) {
+        self.bonus_stage_button.label = if portal.bonus_stage {
+            "Bonus Stage: ON".to_string()
+        } else {
+            "Bonus Stage: OFF".to_string()
+        };
+        self.portal = portal;
+    }
+
+    /// Off -> Perfect -> ... -> Human -> Off
+    pub fn next_autopilot(&self) -> Option<AiDifficulty> {
+        match self.autopilot {
+            None => Some(AiDifficulty::ALL[0]),
+            Some(current) => {
+                let index = AiDifficulty::ALL.iter().position(|&d| d == current).unwrap_or(0);
+                AiDifficulty::ALL.get(index + 1).copied()
+            }
+        }
+    }
+    
     pub fn set_game_started(&mut self, started: bool) {
         self.game_started = started;
         self.resume_button.label = if started {
@@ -346,8 +520,20 @@ pub enum MenuAction {
     ToggleSFX,
     ToggleFullscreen,
     ToggleVSync,
-    ToggleGravity,
+    CycleAutopilot,
+    ToggleAutoLaunch,
+    ToggleHeartRescue,
+    ToggleBonusStage,
+    OpenModifiers,
+    CloseModifiers,
+    ToggleModifier(ModifierKind),
     EnterLevelEditor,
+    OpenModes,
+    CloseModes,
+    OpenCampaigns,
+    CloseCampaigns,
+    SelectPack(usize), // Index into the pack list
+    StartMode(GameMode),
     OpenGithub,
     SelectResolution(usize), // Selected resolution index
     ConfirmResolution,
@@ -368,8 +554,14 @@ pub fn handle_menu_click(menu: &Menu, mouse_x: i32, mouse_y: i32) -> MenuAction
             if menu.restart_button.is_clicked(mouse_x, mouse_y) {
                 return MenuAction::Restart;
             }
-            if menu.gravity_mode_button.is_clicked(mouse_x, mouse_y) {
-                return MenuAction::ToggleGravity;
+            if menu.modes_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::OpenModes;
+            }
+            if menu.campaigns_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::OpenCampaigns;
+            }
+            if menu.modifiers_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::OpenModifiers;
             }
             if menu.level_editor_button.is_clicked(mouse_x, mouse_y) {
                 return MenuAction::EnterLevelEditor;
@@ -409,6 +601,18 @@ pub fn handle_menu_click(menu: &Menu, mouse_x: i32, mouse_y: i32) -> MenuAction
             if menu.vsync_button.is_clicked(mouse_x, mouse_y) {
                 return MenuAction::ToggleVSync;
             }
+            if menu.autopilot_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::CycleAutopilot;
+            }
+            if menu.auto_launch_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::ToggleAutoLaunch;
+            }
+            if menu.heart_rescue_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::ToggleHeartRescue;
+            }
+            if menu.bonus_stage_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::ToggleBonusStage;
+            }
             // Check resolution buttons
             for (i, btn) in menu.resolution_buttons.iter().enumerate() {
                 if btn.is_clicked(mouse_x, mouse_y) {
@@ -419,6 +623,49 @@ pub fn handle_menu_click(menu: &Menu, mouse_x: i32, mouse_y: i32) -> MenuAction
                 return MenuAction::CloseSettings;
             }
         }
+        MenuState::Modes => {
+            if menu.campaign_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::StartMode(GameMode::Campaign);
+            }
+            if menu.time_attack_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::StartMode(GameMode::TimeAttack);
+            }
+            if menu.daily_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::StartMode(GameMode::Daily);
+            }
+            if menu.hot_seat_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::StartMode(GameMode::HotSeat);
+            }
+            if menu.co_op_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::StartMode(GameMode::CoOp);
+            }
+            if menu.versus_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::StartMode(GameMode::Versus);
+            }
+            if menu.modes_back_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::CloseModes;
+            }
+        }
+        MenuState::Modifiers => {
+            for (btn, kind) in menu.modifier_buttons.iter().zip(ModifierKind::ALL) {
+                if btn.is_clicked(mouse_x, mouse_y) {
+                    return MenuAction::ToggleModifier(kind);
+                }
+            }
+            if menu.modifiers_back_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::CloseModifiers;
+            }
+        }
+        MenuState::Campaigns => {
+            for (i, btn) in menu.pack_buttons.iter().enumerate() {
+                if btn.is_clicked(mouse_x, mouse_y) {
+                    return MenuAction::SelectPack(i);
+                }
+            }
+            if menu.campaigns_back_button.is_clicked(mouse_x, mouse_y) {
+                return MenuAction::CloseCampaigns;
+            }
+        }
 
     }
     MenuAction::None
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use crate::entities::*;
+
+/// Gameplay modifiers, as stored in settings.json
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ModifierKind {
+    Gravity,
+    NoSpin,
+    DoubleSpeed,
+    TinyPaddle,
+    OneLife,
+    InvisibleBlocks,
+    MirrorControls,
+}
+
+impl ModifierKind {
+    /// Menu order
+    pub const ALL: [ModifierKind; 7] = [
+        ModifierKind::Gravity,
+        ModifierKind::NoSpin,
+        ModifierKind::DoubleSpeed,
+        ModifierKind::TinyPaddle,
+        ModifierKind::OneLife,
+        ModifierKind::InvisibleBlocks,
+        ModifierKind::MirrorControls,
+    ];
+
+    pub fn mutator(self) -> Box<dyn Mutator> {
+        match self {
+            ModifierKind::Gravity => Box::new(Gravity),
+            ModifierKind::NoSpin => Box::new(NoSpin),
+            ModifierKind::DoubleSpeed => Box::new(DoubleSpeed),
+            ModifierKind::TinyPaddle => Box::new(TinyPaddle),
+            ModifierKind::OneLife => Box::new(OneLife),
+            ModifierKind::InvisibleBlocks => Box::new(InvisibleBlocks),
+            ModifierKind::MirrorControls => Box::new(MirrorControls),
+        }
+    }
+}
+
+/// A single modifier. Every hook defaults to "no change".
+pub trait Mutator {
+    /// Short name for menu and HUD
+    fn name(&self) -> &'static str;
+
+    /// Score multiplier reflecting the extra difficulty
+    fn score_multiplier(&self) -> f32;
+
+    /// Called every frame for a moving ball, before it moves
+    fn ball_physics(&self, _ball: &mut Ball) {}
+
+    /// Whether paddle spin curves the ball
+    fn allows_spin(&self) -> bool {
+        true
+    }
+
+    /// Transform the requested paddle center (mouse control)
+    fn paddle_target(&self, center_x: i32) -> i32 {
+        center_x
+    }
+
+    /// Transform keyboard direction (-1 left, 1 right)
+    fn paddle_direction(&self, direction: i32) -> i32 {
+        direction
+    }
+
+    /// Adjust a freshly created paddle
+    fn setup_paddle(&self, _paddle: &mut Paddle) {}
+
+    fn max_lives(&self) -> u32 {
+        3
+    }
+
+    /// Whether blocks are drawn this frame
+    fn blocks_visible(&self, _frame_count: u64) -> bool {
+        true
+    }
+}
+
+/// Heavier ball that accelerates downward, no spin
+struct Gravity;
+
+impl Mutator for Gravity {
+    fn name(&self) -> &'static str { "Gravity" }
+    fn score_multiplier(&self) -> f32 { 1.25 }
+
+    fn ball_physics(&self, ball: &mut Ball) {
+        // Accelerate downward with terminal velocity
+        const TERMINAL_VELOCITY: f32 = 9.0;
+        if ball.vel_y > 0.0 && ball.vel_y < TERMINAL_VELOCITY {
+            ball.vel_y = (ball.vel_y + 0.3).min(TERMINAL_VELOCITY);
+        }
+    }
+
+    fn allows_spin(&self) -> bool {
+        false
+    }
+}
+
+struct NoSpin;
+
+impl Mutator for NoSpin {
+    fn name(&self) -> &'static str { "No Spin" }
+    fn score_multiplier(&self) -> f32 { 1.1 }
+
+    fn allows_spin(&self) -> bool {
+        false
+    }
+}
+
+/// Ball never drops below twice the launch speed
+struct DoubleSpeed;
+
+impl Mutator for DoubleSpeed {
+    fn name(&self) -> &'static str { "Double Speed" }
+    fn score_multiplier(&self) -> f32 { 1.5 }
+
+    fn ball_physics(&self, ball: &mut Ball) {
+        const MIN_SPEED: f32 = 8.0;
+        let speed = (ball.vel_x.powi(2) + ball.vel_y.powi(2)).sqrt();
+        if speed > 0.0 && speed < MIN_SPEED {
+            ball.vel_x *= MIN_SPEED / speed;
+            ball.vel_y *= MIN_SPEED / speed;
+        }
+    }
+}
+
+struct TinyPaddle;
+
+impl Mutator for TinyPaddle {
+    fn name(&self) -> &'static str { "Tiny Paddle" }
+    fn score_multiplier(&self) -> f32 { 1.4 }
+
+    fn setup_paddle(&self, paddle: &mut Paddle) {
+        paddle.normal_width = PADDLE_WIDTH * 3 / 5;
+        paddle.long_width = paddle.normal_width + 40;
+        paddle.width = paddle.normal_width;
+        paddle.x = (WINDOW_WIDTH as i32 - paddle.width) / 2;
+        paddle.last_x = paddle.x;
+    }
+}
+
+struct OneLife;
+
+impl Mutator for OneLife {
+    fn name(&self) -> &'static str { "One Life" }
+    fn score_multiplier(&self) -> f32 { 1.5 }
+
+    fn max_lives(&self) -> u32 {
+        1
+    }
+}
+
+/// Blocks only flash into view for a moment every 4 seconds
+struct InvisibleBlocks;
+
+impl Mutator for InvisibleBlocks {
+    fn name(&self) -> &'static str { "Invisible Blocks" }
+    fn score_multiplier(&self) -> f32 { 1.5 }
+
+    fn blocks_visible(&self, frame_count: u64) -> bool {
+        frame_count % 240 < 20
+    }
+}
+
+struct MirrorControls;
+
+impl Mutator for MirrorControls {
+    fn name(&self) -> &'static str { "Mirror Controls" }
+    fn score_multiplier(&self) -> f32 { 1.3 }
+
+    fn paddle_target(&self, center_x: i32) -> i32 {
+        WINDOW_WIDTH as i32 - center_x
+    }
+
+    fn paddle_direction(&self, direction: i32) -> i32 {
+        -direction
+    }
+}
+
+/// The set of modifiers active for a run
+#[derive(Default)]
+pub struct Modifiers {
+    active: Vec<Box<dyn Mutator>>,
+}
+
+impl Modifiers {
+    pub fn from_kinds(kinds: &[ModifierKind]) -> Self {
+        Modifiers {
+            active: kinds.iter().map(|kind| kind.mutator()).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.active.iter().map(|m| m.name()).collect()
+    }
+
+    pub fn score_multiplier(&self) -> f32 {
+        self.active.iter().map(|m| m.score_multiplier()).product()
+    }
+
+    /// Apply the difficulty multiplier to a score award
+    pub fn scale_points(&self, points: u32) -> u32 {
+        (points as f32 * self.score_multiplier()).round() as u32
+    }
+
+    pub fn ball_physics(&self, ball: &mut Ball) {
+        for m in &self.active {
+            m.ball_physics(ball);
+        }
+    }
+
+    pub fn allows_spin(&self) -> bool {
+        self.active.iter().all(|m| m.allows_spin())
+    }
+
+    pub fn paddle_target(&self, center_x: i32) -> i32 {
+        self.active.iter().fold(center_x, |x, m| m.paddle_target(x))
+    }
+
+    pub fn paddle_direction(&self, direction: i32) -> i32 {
+        self.active.iter().fold(direction, |d, m| m.paddle_direction(d))
+    }
+
+    pub fn setup_paddle(&self, paddle: &mut Paddle) {
+        for m in &self.active {
+            m.setup_paddle(paddle);
+        }
+    }
+
+    pub fn max_lives(&self) -> u32 {
+        self.active.iter().map(|m| m.max_lives()).min().unwrap_or(3)
+    }
+
+    pub fn blocks_visible(&self, frame_count: u64) -> bool {
+        self.active.iter().all(|m| m.blocks_visible(frame_count))
+    }
+}
+
+/// Combined multiplier for a selection (menu preview)
+pub fn selection_multiplier(kinds: &[ModifierKind]) -> f32 {
+    kinds.iter().map(|kind| kind.mutator().score_multiplier()).product()
+}
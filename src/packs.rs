@@ -0,0 +1,223 @@
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::campaign::{default_bonus_stages, BonusStageDef, Campaign, CampaignLevel, LevelBackground, LevelBlocks};
+use crate::validator::{validate_file, Severity};
+
+const PACKS_DIR: &str = "packs";
+const MANIFEST_FILE: &str = "pack.json";
+
+/// Finds game files pack-first, falling back to the built-in ones.
+/// A pack mirrors the game's layout: `assets/ball.mp3`, `assets/music/`, `patterns/`...
+#[derive(Clone, Default)]
+pub struct AssetResolver {
+    root: Option<PathBuf>, // Pack directory, None for the built-in campaign
+}
+
+impl AssetResolver {
+    pub fn resolve(&self, path: &str) -> String {
+        if let Some(ref root) = self.root {
+            let candidate = root.join(path);
+            if candidate.exists() {
+                return candidate.to_string_lossy().to_string();
+            }
+        }
+        path.to_string()
+    }
+
+    pub fn exists(&self, path: &str) -> bool {
+        Path::new(&self.resolve(path)).exists()
+    }
+}
+
+/// pack.json
+#[derive(Deserialize)]
+struct PackManifest {
+    name: String,
+    #[serde(default)]
+    author: String,
+    #[serde(default)]
+    description: String,
+    levels: Vec<CampaignLevel>,
+    #[serde(default = "default_bonus_stages")]
+    bonus_stages: Vec<BonusStageDef>,
+}
+
+/// A playable campaign: the built-in one or an installed pack
+pub struct Pack {
+    pub id: String, // Directory name under packs/, empty for the built-in campaign
+    pub name: String,
+    pub author: String,
+    pub description: String,
+    pub campaign: Campaign,
+    pub resolver: AssetResolver,
+}
+
+/// A pack that failed to load, listed in the menu with the reason
+pub struct BrokenPack {
+    pub id: String,
+    pub error: String,
+}
+
+pub struct PackList {
+    pub packs: Vec<Pack>, // Built-in campaign first
+    pub broken: Vec<BrokenPack>,
+}
+
+impl PackList {
+    /// Built-in campaign plus everything under packs/. Zip archives are unpacked next to
+    /// themselves on first sight.
+    fn scan() -> Self {
+        let mut list = PackList {
+            packs: vec![Pack {
+                id: String::new(),
+                name: "Arkanoo".to_string(),
+                author: String::new(),
+                description: "The built-in campaign".to_string(),
+                campaign: Campaign::load(),
+                resolver: AssetResolver::default(),
+            }],
+            broken: Vec::new(),
+        };
+
+        let mut entries: Vec<PathBuf> = match fs::read_dir(PACKS_DIR) {
+            Ok(entries) => entries.flatten().map(|entry| entry.path()).collect(),
+            Err(_) => return list,
+        };
+        entries.sort();
+
+        for path in &entries {
+            if path.extension().and_then(|s| s.to_str()) == Some("zip") {
+                let dir = path.with_extension("");
+                if !dir.exists() {
+                    if let Err(e) = install_zip(path, &dir) {
+                        list.report(path, format!("could not unpack: {}", e));
+                    }
+                }
+            }
+        }
+
+        let mut dirs: Vec<PathBuf> = fs::read_dir(PACKS_DIR)
+            .map(|entries| entries.flatten().map(|entry| entry.path()).filter(|path| path.is_dir()).collect())
+            .unwrap_or_default();
+        dirs.sort();
+
+        for dir in dirs {
+            match load_pack(&dir) {
+                Ok(pack) => list.packs.push(pack),
+                Err(error) => list.report(&dir, error),
+            }
+        }
+
+        list
+    }
+
+    fn report(&mut self, path: &Path, error: String) {
+        let id = path.file_name().and_then(|s| s.to_str()).unwrap_or("?").to_string();
+        eprintln!("Broken pack {}: {}", id, error);
+        self.broken.push(BrokenPack { id, error });
+    }
+
+    /// Index of a pack by id, the built-in campaign if it is not installed
+    pub fn find(&self, id: &str) -> usize {
+        self.packs.iter().position(|pack| pack.id == id).unwrap_or(0)
+    }
+}
+
+fn install_zip(archive: &Path, dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let file = fs::File::open(archive)?;
+    let mut zip = zip::ZipArchive::new(file)?;
+    zip.extract(dir)?;
+    Ok(())
+}
+
+/// Load and check a pack directory. The manifest may also sit one folder down,
+/// as zips of a folder tend to unpack.
+fn load_pack(dir: &Path) -> Result<Pack, String> {
+    let id = dir.file_name().and_then(|s| s.to_str()).unwrap_or("?").to_string();
+
+    let mut root = dir.to_path_buf();
+    if !root.join(MANIFEST_FILE).exists() {
+        let nested: Vec<PathBuf> = fs::read_dir(dir)
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.join(MANIFEST_FILE).exists())
+            .collect();
+        match nested.as_slice() {
+            [single] => root = single.clone(),
+            _ => return Err(format!("no {} found", MANIFEST_FILE)),
+        }
+    }
+
+    let content = fs::read_to_string(root.join(MANIFEST_FILE)).map_err(|e| format!("{}: {}", MANIFEST_FILE, e))?;
+    let manifest: PackManifest = serde_json::from_str(&content).map_err(|e| format!("{}: {}", MANIFEST_FILE, e))?;
+    if manifest.levels.is_empty() {
+        return Err("the campaign has no levels".to_string());
+    }
+
+    let resolver = AssetResolver { root: Some(root) };
+    // Bonus stages are checked like levels
+    let levels = manifest.levels.iter().enumerate().map(|(i, level)| (format!("level {}", i + 1), level));
+    let stages = manifest.bonus_stages.iter().enumerate().map(|(i, stage)| (format!("bonus stage {}", i + 1), &stage.level));
+    for (label, level) in levels.chain(stages) {
+        let mut files = Vec::new();
+        if let LevelBlocks::Pattern(ref path) = level.blocks {
+            files.push(path);
+        }
+        if let LevelBackground::Image(ref path) = level.background {
+            files.push(path);
+        }
+        if let Some(ref path) = level.music {
+            files.push(path);
+        }
+        if let Some(missing) = files.into_iter().find(|path| !resolver.exists(path)) {
+            return Err(format!("{} ({}): missing {}", label, level.name, missing));
+        }
+        if let LevelBlocks::Pattern(ref path) = level.blocks {
+            let issues = validate_file(&resolver.resolve(path));
+            if let Some(issue) = issues.iter().find(|issue| issue.severity == Severity::Error) {
+                return Err(format!("{} ({}): {}: {}", label, level.name, path, issue));
+            }
+        }
+    }
+
+    Ok(Pack {
+        id,
+        name: manifest.name,
+        author: manifest.author,
+        description: manifest.description,
+        campaign: Campaign { levels: manifest.levels, bonus_stages: manifest.bonus_stages },
+        resolver,
+    })
+}
+
+static PACKS: Lazy<PackList> = Lazy::new(PackList::scan);
+static SELECTED: AtomicUsize = AtomicUsize::new(0);
+
+/// Installed packs, scanned on first use
+pub fn packs() -> &'static PackList {
+    &PACKS
+}
+
+/// Pack the campaign is played from
+pub fn current() -> &'static Pack {
+    &PACKS.packs[SELECTED.load(Ordering::Relaxed)]
+}
+
+pub fn selected() -> usize {
+    SELECTED.load(Ordering::Relaxed)
+}
+
+pub fn select(index: usize) {
+    if index < PACKS.packs.len() {
+        SELECTED.store(index, Ordering::Relaxed);
+    }
+}
+
+/// Path of a game file, from the current pack if it overrides it
+pub fn resolve(path: &str) -> String {
+    current().resolver.resolve(path)
+}
@@ -0,0 +1,116 @@
+use sdl2::image::SaveSurface;
+use sdl2::pixels::{Color as SdlColor, PixelFormatEnum};
+use sdl2::surface::Surface;
+use crate::cli::PreviewOutput;
+use crate::editor::{create_blocks_from_pattern, PatternCell, PatternData};
+use crate::entities::*;
+use crate::rendering::{create_block_textures, draw_block_with_gradient};
+
+/// Border around the grid in PNG previews
+const MARGIN: i32 = 10;
+
+/// `render-pattern`: preview a level file as a PNG or in the terminal
+pub fn run(input: &str, output: &PreviewOutput) -> Result<(), String> {
+    let pattern = PatternData::load_from_file(input).map_err(|e| format!("{}: {}", input, e))?;
+
+    match output {
+        PreviewOutput::Png(path) => {
+            render_png(&pattern, path)?;
+            println!("{} -> {}", input, path);
+        }
+        PreviewOutput::Ansi => print!("{}", render_terminal(&pattern, true)),
+        PreviewOutput::Ascii => print!("{}", render_terminal(&pattern, false)),
+    }
+    Ok(())
+}
+
+/// Draw the pattern's grid with the game's block textures into a PNG. SDL's
+/// software renderer draws into a plain surface, so no window is opened.
+pub fn render_png(pattern: &PatternData, path: &str) -> Result<(), String> {
+    let layout = pattern.layout;
+    let width = (layout.width() + MARGIN * 2) as u32;
+    let height = (layout.height() + MARGIN * 2) as u32;
+
+    let surface = Surface::new(width, height, PixelFormatEnum::RGBA8888)?;
+    let mut canvas = surface.into_canvas()?;
+    let texture_creator = canvas.texture_creator();
+    let block_textures = create_block_textures(&mut canvas, &texture_creator)?;
+
+    canvas.set_draw_color(SdlColor::RGB(10, 10, 20));
+    canvas.clear();
+    for mut block in create_blocks_from_pattern(pattern) {
+        // Playfield position to image position
+        block.x -= layout.offset_x() - MARGIN;
+        block.y -= BLOCK_OFFSET_Y - MARGIN;
+        draw_block_with_gradient(&mut canvas, &block, &block_textures, 0);
+    }
+    canvas.present();
+
+    canvas.into_surface().save(path)
+}
+
+/// Text preview: a header line, then one line per row. With `color` each block
+/// is an ANSI true-color swatch; without, the ASCII level glyphs are used.
+pub fn render_terminal(pattern: &PatternData, color: bool) -> String {
+    let layout = pattern.layout;
+    let mut out = format!(
+        "{} - {}x{} grid, {} blocks\n",
+        if pattern.meta.title.is_empty() { &pattern.name } else { &pattern.meta.title },
+        layout.cols,
+        layout.rows,
+        pattern.block_count()
+    );
+
+    for row in &pattern.grid {
+        for cell in row {
+            match (cell, color) {
+                (Some(cell), true) => {
+                    let (r, g, b) = swatch(cell);
+                    let mark = match cell.block_type {
+                        BlockType::Explosive => "**",
+                        BlockType::Undestroyable => "##",
+                        _ => "  ",
+                    };
+                    out.push_str(&format!("\x1b[48;2;{};{};{}m\x1b[38;2;0;0;0m{}\x1b[0m", r, g, b, mark));
+                }
+                (None, true) => out.push_str("\x1b[2m· \x1b[0m"),
+                (Some(cell), false) => out.push(glyph(cell)),
+                (None, false) => out.push('*'),
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Swatch color, close to how the block looks in game
+fn swatch(cell: &PatternCell) -> (u8, u8, u8) {
+    match cell.block_type {
+        BlockType::Normal => (cell.color.r, cell.color.g, cell.color.b),
+        BlockType::Ice => (200, 240, 255),
+        BlockType::Explosive => (255, 100, 0),
+        BlockType::Undestroyable => (90, 95, 105),
+    }
+}
+
+/// ASCII level glyph; custom colors take the nearest palette digit
+fn glyph(cell: &PatternCell) -> char {
+    match cell.block_type {
+        BlockType::Normal => {
+            let distance = |palette: &Color| {
+                let (dr, dg, db) = (
+                    palette.r as i32 - cell.color.r as i32,
+                    palette.g as i32 - cell.color.g as i32,
+                    palette.b as i32 - cell.color.b as i32,
+                );
+                dr * dr + dg * dg + db * db
+            };
+            let (index, _) = BLOCK_COLORS.iter().enumerate().min_by_key(|(_, palette)| distance(palette)).unwrap();
+            char::from_digit(index as u32, 10).unwrap()
+        }
+        BlockType::Ice => '6',
+        BlockType::Explosive => '7',
+        BlockType::Undestroyable => '8',
+    }
+}
@@ -1,11 +1,17 @@
-use sdl2::render::{Canvas, Texture, TextureCreator};
+use sdl2::render::{Canvas, RenderTarget, Texture, TextureCreator};
 use sdl2::video::{Window, WindowContext};
 use sdl2::pixels::{Color as SdlColor, PixelFormatEnum};
 use sdl2::rect::{Rect, Point};
 use sdl2::ttf::Font;
-use crate::game::{Game, GameState};
+use crate::game::{Game, GameState, GameMode, VERSUS_GOALS};
+use crate::time_attack::*;
+use crate::daily::{DailyChallenge, DAILY_LEVELS};
+use crate::modifiers::*;
 use crate::entities::*;
 use crate::menu::{Menu, MenuState, Button, VolumeSlider};
+use crate::boss::*;
+use crate::effects::{ActiveEffects, EffectKind};
+use crate::campaign::LevelBackground;
 
 pub struct TextureCache<'a> {
     pub ball: Texture<'a>,
@@ -52,20 +58,7 @@ impl<'a> TextureCache<'a> {
             draw_paddle_texture(canvas, long_width, PADDLE_HEIGHT);
         }).map_err(|e| e.to_string())?;
 
-        // Create block textures
-        let mut blocks = Vec::new();
-        for color in BLOCK_COLORS.iter() {
-            let mut block = texture_creator.create_texture_target(PixelFormatEnum::RGBA8888, BLOCK_WIDTH as u32, BLOCK_HEIGHT as u32)
-                .map_err(|e| e.to_string())?;
-            block.set_blend_mode(sdl2::render::BlendMode::Blend);
-
-            canvas.with_texture_canvas(&mut block, |canvas| {
-                canvas.set_draw_color(SdlColor::RGBA(0, 0, 0, 0));
-                canvas.clear();
-                draw_block_texture(canvas, *color);
-            }).map_err(|e| e.to_string())?;
-            blocks.push(block);
-        }
+        let blocks = create_block_textures(canvas, texture_creator)?;
 
         Ok(TextureCache {
             ball,
@@ -79,6 +72,25 @@ impl<'a> TextureCache<'a> {
     }
 }
 
+/// One block texture per palette color, for any render target (the window, or
+/// a software surface for pattern previews)
+pub fn create_block_textures<'a, T: RenderTarget, C>(canvas: &mut Canvas<T>, texture_creator: &'a TextureCreator<C>) -> Result<Vec<Texture<'a>>, String> {
+    let mut blocks = Vec::new();
+    for color in BLOCK_COLORS.iter() {
+        let mut block = texture_creator.create_texture_target(PixelFormatEnum::RGBA8888, BLOCK_WIDTH as u32, BLOCK_HEIGHT as u32)
+            .map_err(|e| e.to_string())?;
+        block.set_blend_mode(sdl2::render::BlendMode::Blend);
+
+        canvas.with_texture_canvas(&mut block, |canvas| {
+            canvas.set_draw_color(SdlColor::RGBA(0, 0, 0, 0));
+            canvas.clear();
+            draw_block_texture(canvas, *color);
+        }).map_err(|e| e.to_string())?;
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
 // Helper functions for texture generation (moved from original draw functions)
 
 fn draw_shiny_ball_texture(canvas: &mut Canvas<Window>) {
@@ -206,7 +218,7 @@ fn draw_paddle_texture(canvas: &mut Canvas<Window>, w: i32, h: i32) {
     }
 }
 
-fn draw_block_texture(canvas: &mut Canvas<Window>, color: Color) {
+fn draw_block_texture<T: RenderTarget>(canvas: &mut Canvas<T>, color: Color) {
     let w = BLOCK_WIDTH;
     let h = BLOCK_HEIGHT;
     
@@ -448,7 +460,7 @@ fn draw_heart(canvas: &mut Canvas<Window>, cx: i32, cy: i32, size: i32) {
 }
 
 /// Draw block with "eye candy" aesthetics (3D bevel, metallic shine)
-fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &TextureCache, frame_count: u64) {
+pub fn draw_block_with_gradient<T: RenderTarget>(canvas: &mut Canvas<T>, block: &Block, block_textures: &[Texture], frame_count: u64) {
     match block.block_type {
         BlockType::Ice => {
             // Ice Block: Realistic Ice Effect
@@ -465,8 +477,8 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
             
             // Icy texture / Glint
             canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 150));
-            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x + BLOCK_WIDTH, block.y));
-            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x, block.y + BLOCK_HEIGHT));
+            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x + block.width, block.y));
+            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x, block.y + block.height));
             
             // Random-looking internal refraction lines (static based on position)
             let seed = (block.x * block.y) as u64;
@@ -475,10 +487,10 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
             
             canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 80));
             for _ in 0..3 {
-                let x1 = block.x + rng.gen_range(5..BLOCK_WIDTH-5);
-                let y1 = block.y + rng.gen_range(5..BLOCK_HEIGHT-5);
-                let x2 = block.x + rng.gen_range(5..BLOCK_WIDTH-5);
-                let y2 = block.y + rng.gen_range(5..BLOCK_HEIGHT-5);
+                let x1 = block.x + rng.gen_range(5..block.width-5);
+                let y1 = block.y + rng.gen_range(5..block.height-5);
+                let x2 = block.x + rng.gen_range(5..block.width-5);
+                let y2 = block.y + rng.gen_range(5..block.height-5);
                 let _ = canvas.draw_line(Point::new(x1, y1), Point::new(x2, y2));
             }
 
@@ -487,8 +499,8 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
                 canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 220));
                 let bx = block.x;
                 let by = block.y;
-                let w = BLOCK_WIDTH;
-                let h = BLOCK_HEIGHT;
+                let w = block.width;
+                let h = block.height;
                 
                 // Main crack
                 let cx = bx + w / 2;
@@ -506,15 +518,15 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
             
             // Moving shine effect
             let shine_speed = 2.0;
-            let total_width = BLOCK_WIDTH + BLOCK_HEIGHT + 40;
+            let total_width = block.width + block.height + 40;
             let shine_pos = (frame_count as f32 * shine_speed) as i32 % total_width;
-            let shine_offset = shine_pos - BLOCK_HEIGHT;
+            let shine_offset = shine_pos - block.height;
             
             canvas.set_clip_rect(block.rect());
             canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 150));
             // Draw diagonal band
             for i in 0..10 {
-                let p1 = Point::new(block.x + shine_offset + i, block.y + BLOCK_HEIGHT);
+                let p1 = Point::new(block.x + shine_offset + i, block.y + block.height);
                 let p2 = Point::new(block.x + shine_offset + 20 + i, block.y);
                 let _ = canvas.draw_line(p1, p2);
             }
@@ -553,8 +565,8 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
             let blob_rect = Rect::new(
                 block.x + 10, 
                 block.y + 10 + blob_offset_y as i32, 
-                (BLOCK_WIDTH - 20) as u32, 
-                (BLOCK_HEIGHT - 20) as u32
+                (block.width - 20).max(0) as u32, 
+                (block.height - 20).max(0) as u32
             );
             canvas.set_draw_color(SdlColor::RGB(255, 150, 50)); // Brighter orange core
             canvas.fill_rect(blob_rect).ok();
@@ -562,30 +574,30 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
             // Glass overlay (Fire behind glass effect)
             // Draw a semi-transparent glossy layer on top
             canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 40));
-            let _ = canvas.fill_rect(Rect::new(block.x, block.y, BLOCK_WIDTH as u32, (BLOCK_HEIGHT/2) as u32));
+            let _ = canvas.fill_rect(Rect::new(block.x, block.y, block.width as u32, (block.height/2) as u32));
             
             // Stronger shine on top edge
             canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 180));
-            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x + BLOCK_WIDTH, block.y));
-            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x, block.y + BLOCK_HEIGHT));
+            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x + block.width, block.y));
+            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x, block.y + block.height));
             
             // Glass reflection diagonal (Shiny thingy)
             canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 80));
-            let _ = canvas.draw_line(Point::new(block.x, block.y + BLOCK_HEIGHT), Point::new(block.x + 20, block.y));
-            let _ = canvas.draw_line(Point::new(block.x + 5, block.y + BLOCK_HEIGHT), Point::new(block.x + 25, block.y));
+            let _ = canvas.draw_line(Point::new(block.x, block.y + block.height), Point::new(block.x + 20, block.y));
+            let _ = canvas.draw_line(Point::new(block.x + 5, block.y + block.height), Point::new(block.x + 25, block.y));
             
             // Moving shine effect (same as Ice block but maybe slower/different?)
             // Let's keep it consistent or slightly different. User asked for "this shiny thingy as in ice blocks"
             let shine_speed = 1.5; // Slightly slower than ice
-            let total_width = BLOCK_WIDTH + BLOCK_HEIGHT + 40;
+            let total_width = block.width + block.height + 40;
             let shine_pos = (frame_count as f32 * shine_speed) as i32 % total_width;
-            let shine_offset = shine_pos - BLOCK_HEIGHT;
+            let shine_offset = shine_pos - block.height;
             
             canvas.set_clip_rect(block.rect());
             canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 100)); // Slightly less intense than ice
             // Draw diagonal band
             for i in 0..8 {
-                let p1 = Point::new(block.x + shine_offset + i, block.y + BLOCK_HEIGHT);
+                let p1 = Point::new(block.x + shine_offset + i, block.y + block.height);
                 let p2 = Point::new(block.x + shine_offset + 20 + i, block.y);
                 let _ = canvas.draw_line(p1, p2);
             }
@@ -601,11 +613,11 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
             
             // Metallic shine (diagonal)
             canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
-            for i in 0..BLOCK_WIDTH + BLOCK_HEIGHT {
+            for i in 0..block.width + block.height {
                 if i % 10 == 0 {
                     canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 30));
-                    let start_x = block.x + i - BLOCK_HEIGHT;
-                    let start_y = block.y + BLOCK_HEIGHT;
+                    let start_x = block.x + i - block.height;
+                    let start_y = block.y + block.height;
                     let end_x = block.x + i;
                     let end_y = block.y;
                     let _ = canvas.draw_line(Point::new(start_x, start_y), Point::new(end_x, end_y));
@@ -615,24 +627,33 @@ fn draw_block_with_gradient(canvas: &mut Canvas<Window>, block: &Block, cache: &
             // Rivets (Steel color)
             canvas.set_draw_color(SdlColor::RGB(150, 150, 160));
             let _ = canvas.fill_rect(Rect::new(block.x + 2, block.y + 2, 3, 3));
-            let _ = canvas.fill_rect(Rect::new(block.x + BLOCK_WIDTH - 5, block.y + 2, 3, 3));
-            let _ = canvas.fill_rect(Rect::new(block.x + 2, block.y + BLOCK_HEIGHT - 5, 3, 3));
-            let _ = canvas.fill_rect(Rect::new(block.x + BLOCK_WIDTH - 5, block.y + BLOCK_HEIGHT - 5, 3, 3));
+            let _ = canvas.fill_rect(Rect::new(block.x + block.width - 5, block.y + 2, 3, 3));
+            let _ = canvas.fill_rect(Rect::new(block.x + 2, block.y + block.height - 5, 3, 3));
+            let _ = canvas.fill_rect(Rect::new(block.x + block.width - 5, block.y + block.height - 5, 3, 3));
             
             // Bevel edge
             canvas.set_draw_color(SdlColor::RGBA(200, 200, 210, 100));
-            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x + BLOCK_WIDTH, block.y));
-            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x, block.y + BLOCK_HEIGHT));
+            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x + block.width, block.y));
+            let _ = canvas.draw_line(Point::new(block.x, block.y), Point::new(block.x, block.y + block.height));
             
             canvas.set_draw_color(SdlColor::RGBA(0, 0, 0, 150));
-            let _ = canvas.draw_line(Point::new(block.x, block.y + BLOCK_HEIGHT - 1), Point::new(block.x + BLOCK_WIDTH, block.y + BLOCK_HEIGHT - 1));
-            let _ = canvas.draw_line(Point::new(block.x + BLOCK_WIDTH - 1, block.y), Point::new(block.x + BLOCK_WIDTH - 1, block.y + BLOCK_HEIGHT));
+            let _ = canvas.draw_line(Point::new(block.x, block.y + block.height - 1), Point::new(block.x + block.width, block.y + block.height - 1));
+            let _ = canvas.draw_line(Point::new(block.x + block.width - 1, block.y), Point::new(block.x + block.width - 1, block.y + block.height));
             
             canvas.set_blend_mode(sdl2::render::BlendMode::None);
         },
         BlockType::Normal => {
-            let color_idx = BLOCK_COLORS.iter().position(|&c| c.r == block.color.r && c.g == block.color.g && c.b == block.color.b).unwrap_or(0);
-            let _ = canvas.copy(&cache.blocks[color_idx], None, Some(block.rect()));
+            if let Some(color_idx) = BLOCK_COLORS.iter().position(|&c| c == block.color) {
+                let _ = canvas.copy(&block_textures[color_idx], None, Some(block.rect()));
+            } else {
+                // Custom color from a v2 level - flat block with a light edge
+                canvas.set_draw_color(SdlColor::RGB(block.color.r, block.color.g, block.color.b));
+                let _ = canvas.fill_rect(block.rect());
+                canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+                canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 90));
+                let _ = canvas.draw_rect(block.rect());
+                canvas.set_blend_mode(sdl2::render::BlendMode::None);
+            }
         }
     }
 }
@@ -697,6 +718,82 @@ fn draw_paddle_with_glass(canvas: &mut Canvas<Window>, paddle: &Paddle, cache: &
     canvas.set_blend_mode(sdl2::render::BlendMode::None);
 }
 
+/// Row of active effect icons with remaining-time bars and stack counts
+fn draw_effects_strip(canvas: &mut Canvas<Window>, effects: &ActiveEffects, label: Option<String>, y: i32, font: &Font) {
+    if effects.is_empty() {
+        return;
+    }
+
+    let mut x = 10;
+    if let Some(label) = label {
+        if let Ok(surface) = font.render(&label).blended(SdlColor::RGB(200, 200, 200)) {
+            let texture_creator = canvas.texture_creator();
+            if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                let target = Rect::new(x, y, surface.width(), surface.height());
+                let _ = canvas.copy(&texture, None, Some(target));
+                x += surface.width() as i32 + 8;
+            };
+        }
+    }
+
+    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+    for effect in effects.iter() {
+        let color = effect.kind.color();
+        let icon_size: u32 = 28;
+
+        // Tinted glass tile with the capsule symbol
+        canvas.set_draw_color(SdlColor::RGBA(color.r, color.g, color.b, 90));
+        let _ = canvas.fill_rect(Rect::new(x, y, icon_size, icon_size));
+        canvas.set_draw_color(SdlColor::RGBA(color.r, color.g, color.b, 220));
+        let _ = canvas.draw_rect(Rect::new(x, y, icon_size, icon_size));
+
+        let cx = x + icon_size as i32 / 2;
+        let cy = y + icon_size as i32 / 2;
+        canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 255));
+        let _ = match effect.kind {
+            EffectKind::LongPaddle => canvas.fill_rect(Rect::new(cx - 8, cy - 2, 16, 5)),
+            EffectKind::GhostBall => canvas.fill_rect(Rect::new(cx - 6, cy - 6, 12, 12)),
+            EffectKind::Rockets => canvas.fill_rect(Rect::new(cx - 3, cy - 6, 6, 12)),
+            EffectKind::Shrink => canvas.fill_rect(Rect::new(cx - 4, cy - 2, 8, 5)),
+        };
+
+        // Remaining time bar under the icon (full for rockets, which don't run out)
+        let bar_y = y + icon_size as i32 + 3;
+        canvas.set_draw_color(SdlColor::RGBA(40, 40, 40, 200));
+        let _ = canvas.fill_rect(Rect::new(x, bar_y, icon_size, 4));
+        let filled = (icon_size as f32 * effect.progress()) as u32;
+        if filled > 0 {
+            canvas.set_draw_color(SdlColor::RGBA(color.r, color.g, color.b, 255));
+            let _ = canvas.fill_rect(Rect::new(x, bar_y, filled, 4));
+        }
+        x += icon_size as i32 + 6;
+
+        // Stack count
+        if effect.stacks > 1 {
+            let text = format!("x{}", effect.stacks);
+            if let Ok(surface) = font.render(&text).blended(SdlColor::RGB(255, 255, 255)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(x, y, surface.width(), surface.height());
+                    let _ = canvas.copy(&texture, None, Some(target));
+                    x += surface.width() as i32 + 6;
+                };
+            }
+        }
+        x += 6;
+    }
+    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+}
+
+/// Small blocky heart centered on (cx, cy), in the current draw color
+fn draw_heart_symbol(canvas: &mut Canvas<Window>, cx: i32, cy: i32) {
+    let _ = canvas.fill_rect(Rect::new(cx - 7, cy - 6, 6, 5));
+    let _ = canvas.fill_rect(Rect::new(cx + 1, cy - 6, 6, 5));
+    let _ = canvas.fill_rect(Rect::new(cx - 8, cy - 2, 16, 4));
+    let _ = canvas.fill_rect(Rect::new(cx - 5, cy + 2, 10, 3));
+    let _ = canvas.fill_rect(Rect::new(cx - 2, cy + 5, 4, 2));
+}
+
 /// Draw a clean glass capsule/bulb with symbol inside
 fn draw_bonus_icon(canvas: &mut Canvas<Window>, bonus: &Bonus) {
     let rect = bonus.rect();
@@ -712,6 +809,9 @@ fn draw_bonus_icon(canvas: &mut Canvas<Window>, bonus: &Bonus) {
         BonusType::LongPaddle => (100, 255, 100), // Green
         BonusType::GhostBall => (200, 200, 200),  // Grey
         BonusType::Rocket => (255, 165, 0),       // Orange
+        BonusType::Shrink => (160, 40, 200),      // Purple
+        BonusType::SpeedUp => (255, 255, 60),     // Yellow
+        BonusType::Heart => (220, 20, 60),        // Crimson
     };
 
     // Draw capsule body - transparent glass with color tint
@@ -804,6 +904,19 @@ fn draw_bonus_icon(canvas: &mut Canvas<Window>, bonus: &Bonus) {
             // Rocket shadow (triangle)
             let _ = canvas.fill_rect(Rect::new(cx - 3, cy - 6 + 1, 6, 12));
         }
+        BonusType::Shrink => {
+            // Short bar shadow
+            let _ = canvas.fill_rect(Rect::new(cx - 4, cy - 2 + 1, 8, 5));
+        }
+        BonusType::SpeedUp => {
+            // Double bar shadow
+            let _ = canvas.fill_rect(Rect::new(cx - 6, cy - 6 + 1, 4, 12));
+            let _ = canvas.fill_rect(Rect::new(cx + 2, cy - 6 + 1, 4, 12));
+        }
+        BonusType::Heart => {
+            // Heart shadow
+            draw_heart_symbol(canvas, cx, cy + 1);
+        }
     }
     
     // Actual symbol (bright and clear)
@@ -832,6 +945,19 @@ fn draw_bonus_icon(canvas: &mut Canvas<Window>, bonus: &Bonus) {
             // Rocket (triangle)
             let _ = canvas.fill_rect(Rect::new(cx - 3, cy - 6, 6, 12));
         }
+        BonusType::Shrink => {
+            // Short bar
+            let _ = canvas.fill_rect(Rect::new(cx - 4, cy - 2, 8, 5));
+        }
+        BonusType::SpeedUp => {
+            // Double bar
+            let _ = canvas.fill_rect(Rect::new(cx - 6, cy - 6, 4, 12));
+            let _ = canvas.fill_rect(Rect::new(cx + 2, cy - 6, 4, 12));
+        }
+        BonusType::Heart => {
+            // Heart
+            draw_heart_symbol(canvas, cx, cy);
+        }
     }
     
     // Actual symbol (bright and clear)
@@ -860,12 +986,25 @@ fn draw_bonus_icon(canvas: &mut Canvas<Window>, bonus: &Bonus) {
             // Rocket
             let _ = canvas.fill_rect(Rect::new(cx - 3, cy - 6, 6, 12));
         }
+        BonusType::Shrink => {
+            // Short bar
+            let _ = canvas.fill_rect(Rect::new(cx - 4, cy - 2, 8, 5));
+        }
+        BonusType::SpeedUp => {
+            // Double bar
+            let _ = canvas.fill_rect(Rect::new(cx - 6, cy - 6, 4, 12));
+            let _ = canvas.fill_rect(Rect::new(cx + 2, cy - 6, 4, 12));
+        }
+        BonusType::Heart => {
+            // Heart
+            draw_heart_symbol(canvas, cx, cy);
+        }
     }
     
     canvas.set_blend_mode(sdl2::render::BlendMode::None);
 }
 
-/// Draw animated background for levels 7-9
+/// Draw an animated background theme: 7-9 are handcrafted, any other id picks a seeded theme
 fn draw_animated_background(canvas: &mut Canvas<Window>, level: usize, frame: u64) {
     // Use frame counter for animation timing
     let time = frame as f32;
@@ -1855,6 +1994,7 @@ pub fn render_game(
     font: &Font,
     fps: f32,
     splash_timer: u64,
+    attract: bool, // Game is the title screen demo
     cache: &mut TextureCache,
 ) {
     // Handle splash screen state
@@ -1890,11 +2030,11 @@ pub fn render_game(
     }
 
     // Draw background
-    if game.current_level > 6 {
-        // Animated backgrounds for levels 7-9
-        draw_animated_background(canvas, game.current_level, game.frame_count);
+    if let LevelBackground::Theme(theme) = game.background() {
+        // Animated procedural theme
+        draw_animated_background(canvas, theme, game.frame_count);
     } else {
-        // Image backgrounds for levels 1-6
+        // Image background (loaded by the caller)
         canvas.set_draw_color(SdlColor::RGB(0, 0, 0));
         canvas.clear();
 
@@ -1908,38 +2048,70 @@ pub fn render_game(
         }
     }
 
-    // Draw blocks with gradient and glass effects
-    for block in &game.blocks {
-        if block.active {
-            draw_block_with_gradient(canvas, block, cache, game.frame_count);
+    // Draw blocks with gradient and glass effects (a modifier may hide them)
+    if game.modifiers.blocks_visible(game.frame_count) {
+        for block in &game.blocks {
+            if block.active && block.shake > 0 {
+                // Knocked by a shockwave - draw a jittered copy
+                let (dx, dy) = block.shake_offset();
+                let mut shaken = block.clone();
+                shaken.x += dx;
+                shaken.y += dy;
+                draw_block_with_gradient(canvas, &shaken, &cache.blocks, game.frame_count);
+            } else if block.active {
+                draw_block_with_gradient(canvas, block, &cache.blocks, game.frame_count);
+            }
         }
     }
 
-    // Draw paddle with glass effect
-    draw_paddle_with_glass(canvas, &game.paddle, cache);
-    
-    // Draw cannon on paddle if rocket ammo is available
-    if game.paddle.rocket_ammo > 0 {
-        let cannon_x = game.paddle.x + game.paddle.width / 2 - 5;
-        let cannon_y = game.paddle.y - 15;
-        
-        // Cannon barrel (dark grey)
-        canvas.set_draw_color(SdlColor::RGB(100, 100, 100));
-        let _ = canvas.fill_rect(Rect::new(cannon_x, cannon_y, 10, 15));
-        
-        // Cannon highlight
-        canvas.set_draw_color(SdlColor::RGB(150, 150, 150));
-        let _ = canvas.fill_rect(Rect::new(cannon_x + 2, cannon_y, 2, 15));
-        
-        // Blinking text: "press space to launch"
-        // Blink every 30 frames (about 0.5 seconds at 60 FPS)
-        if (game.frame_count / 30).is_multiple_of(2) {
-            let text = "press space to launch";
-            if let Ok(surface) = font.render(text).blended(SdlColor::RGB(255, 255, 100)) {
+    // Draw boss
+    if let Some(ref boss) = game.boss {
+        draw_boss(canvas, boss);
+    }
+
+    // Draw paddles with glass effect (second paddle only in co-op)
+    let paddles = std::iter::once((&game.paddle, "space")).chain(game.paddle2.iter().map(|p| (p, "W")));
+    for (index, (paddle, launch_key)) in paddles.enumerate() {
+        draw_paddle_with_glass(canvas, paddle, cache);
+
+        // Draw cannon on paddle if rocket ammo is available (facing the wall)
+        if paddle.rocket_ammo() > 0 {
+            let cannon_x = paddle.x + paddle.width / 2 - 5;
+            let cannon_y = if paddle.is_top() { paddle.y + PADDLE_HEIGHT } else { paddle.y - 15 };
+
+            // Cannon barrel (dark grey)
+            canvas.set_draw_color(SdlColor::RGB(100, 100, 100));
+            let _ = canvas.fill_rect(Rect::new(cannon_x, cannon_y, 10, 15));
+
+            // Cannon highlight
+            canvas.set_draw_color(SdlColor::RGB(150, 150, 150));
+            let _ = canvas.fill_rect(Rect::new(cannon_x + 2, cannon_y, 2, 15));
+
+            // Blinking text: "press space to launch"
+            // Blink every 30 frames (about 0.5 seconds at 60 FPS)
+            if (game.frame_count / 30).is_multiple_of(2) {
+                let text = format!("press {} to launch", launch_key);
+                if let Ok(surface) = font.render(&text).blended(SdlColor::RGB(255, 255, 100)) {
+                    let texture_creator = canvas.texture_creator();
+                    if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                        let text_x = paddle.x + paddle.width / 2 - surface.width() as i32 / 2;
+                        let text_y = if paddle.is_top() { paddle.y + PADDLE_HEIGHT + 20 } else { paddle.y - 35 };
+                        let target = Rect::new(text_x, text_y, surface.width(), surface.height());
+                        let _ = canvas.copy(&texture, None, Some(target));
+                    };
+                }
+            }
+        }
+
+        // Co-op: label each paddle with its player
+        if game.paddle2.is_some() {
+            let label = format!("P{}", index + 1);
+            if let Ok(surface) = font.render(&label).blended(SdlColor::RGB(200, 200, 200)) {
                 let texture_creator = canvas.texture_creator();
                 if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
-                    let text_x = game.paddle.x + game.paddle.width / 2 - surface.width() as i32 / 2;
-                    let text_y = game.paddle.y - 35;
+                    let text_x = paddle.x + paddle.width / 2 - surface.width() as i32 / 2;
+                    // Behind the paddle, away from the ball
+                    let text_y = if paddle.is_top() { paddle.y - surface.height() as i32 - 2 } else { paddle.y + PADDLE_HEIGHT + 2 };
                     let target = Rect::new(text_x, text_y, surface.width(), surface.height());
                     let _ = canvas.copy(&texture, None, Some(target));
                 };
@@ -1947,6 +2119,13 @@ pub fn render_game(
         }
     }
 
+    // Draw launch previews for balls resting on a paddle
+    if game.state == GameState::Playing {
+        for ball in game.balls.iter().filter(|b| b.active && b.attached_to_paddle) {
+            draw_aim_preview(canvas, &game.aim_preview(ball));
+        }
+    }
+
     // Draw balls (shiny circular metal balls)
     for ball in &game.balls {
         if ball.active {
@@ -1966,42 +2145,61 @@ pub fn render_game(
         if rocket.active {
             let rx = rocket.x as i32;
             let ry = rocket.y as i32;
+
+            // Rockets fired from the top paddle (versus) are drawn upside down
+            let down = rocket.vel_y > 0.0;
+            let row = |dy: i32, h: i32| if down { ry + 20 - dy - h } else { ry + dy };
             
             // Rocket body (orange)
             canvas.set_draw_color(SdlColor::RGB(255, 100, 0));
-            let _ = canvas.fill_rect(Rect::new(rx + 2, ry + 4, 6, 12));
+            let _ = canvas.fill_rect(Rect::new(rx + 2, row(4, 12), 6, 12));
             
             // Rocket nose cone (red triangle)
             canvas.set_draw_color(SdlColor::RGB(255, 50, 50));
             for i in 0..4 {
                 let _ = canvas.draw_line(
-                    Point::new(rx + 5, ry + i),
-                    Point::new(rx + 2 + i, ry + 4),
+                    Point::new(rx + 5, row(i, 1)),
+                    Point::new(rx + 2 + i, row(4, 1)),
                 );
                 let _ = canvas.draw_line(
-                    Point::new(rx + 5, ry + i),
-                    Point::new(rx + 8 - i, ry + 4),
+                    Point::new(rx + 5, row(i, 1)),
+                    Point::new(rx + 8 - i, row(4, 1)),
                 );
             }
             
             // Rocket fins (dark orange)
             canvas.set_draw_color(SdlColor::RGB(200, 80, 0));
-            let _ = canvas.fill_rect(Rect::new(rx, ry + 12, 3, 4));      // Left fin
-            let _ = canvas.fill_rect(Rect::new(rx + 7, ry + 12, 3, 4));  // Right fin
+            let _ = canvas.fill_rect(Rect::new(rx, row(12, 4), 3, 4));      // Left fin
+            let _ = canvas.fill_rect(Rect::new(rx + 7, row(12, 4), 3, 4));  // Right fin
             
             // Flame trail (yellow/orange gradient)
             canvas.set_draw_color(SdlColor::RGB(255, 255, 100));
-            let _ = canvas.fill_rect(Rect::new(rx + 3, ry + 16, 4, 2));
+            let _ = canvas.fill_rect(Rect::new(rx + 3, row(16, 2), 4, 2));
             canvas.set_draw_color(SdlColor::RGB(255, 200, 50));
-            let _ = canvas.fill_rect(Rect::new(rx + 3, ry + 18, 4, 2));
+            let _ = canvas.fill_rect(Rect::new(rx + 3, row(18, 2), 4, 2));
+        }
+    }
+
+    // Draw boss projectiles
+    for projectile in &game.boss_projectiles {
+        if projectile.active {
+            draw_boss_projectile(canvas, projectile, game.frame_count);
         }
     }
 
     // Draw particles
     canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+    for shockwave in &game.shockwaves {
+        draw_shockwave(canvas, shockwave);
+    }
     for particle in &game.particles {
         draw_particle(canvas, particle);
     }
+
+    // Draw floating score popups
+    for popup in &game.score_popups {
+        draw_score_popup(canvas, popup, font);
+    }
     
     // Draw penguin animation if active
     if let Some(ref penguin) = game.penguin {
@@ -2016,7 +2214,7 @@ pub fn render_game(
     }
 
     // Draw HUD
-    render_hud(canvas, game, heart_texture, font, fps);
+    render_hud(canvas, game, menu, heart_texture, font, fps);
 
     // Draw menu if paused or game over
     if game.state == GameState::Paused {
@@ -2027,11 +2225,232 @@ pub fn render_game(
         render_victory_menu(canvas, game, font);
     } else if game.state == GameState::LevelTransition {
         render_level_transition(canvas, game, font);
+    } else if game.state == GameState::PlayerReady {
+        render_player_ready(canvas, game, font);
+    }
+
+    if attract {
+        render_attract_overlay(canvas, game, font);
     }
 
     canvas.present();
 }
 
+/// Title screen demo: dim the playfield and invite the player in
+fn render_attract_overlay(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
+    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+    canvas.set_draw_color(SdlColor::RGBA(0, 0, 0, 110));
+    let _ = canvas.fill_rect(Rect::new(0, 0, WINDOW_WIDTH, WINDOW_HEIGHT));
+    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+
+    if let Ok(surface) = font.render("DEMO").blended(SdlColor::RGB(100, 200, 255)) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(
+                WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                WINDOW_HEIGHT as i32 / 2 - 60,
+                surface.width(),
+                surface.height(),
+            );
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+
+    // Blink every 40 frames
+    if (game.frame_count / 40).is_multiple_of(2) {
+        if let Ok(surface) = font.render("PRESS ANY KEY").blended(SdlColor::RGB(255, 255, 255)) {
+            let texture_creator = canvas.texture_creator();
+            if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                let target = Rect::new(
+                    WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                    WINDOW_HEIGHT as i32 / 2 - 20,
+                    surface.width(),
+                    surface.height(),
+                );
+                let _ = canvas.copy(&texture, None, Some(target));
+            };
+        }
+    }
+}
+
+/// Draw the boss: armored core with a glowing eye, hit-flash and death shake
+fn draw_boss(canvas: &mut Canvas<Window>, boss: &Boss) {
+    let rect = boss.rect();
+    let mut x = rect.x();
+    let mut y = rect.y();
+
+    // Shake violently while dying
+    if boss.is_defeated() {
+        x += ((boss.death_timer as f32 * 1.7).sin() * 6.0) as i32;
+        y += ((boss.death_timer as f32 * 2.3).cos() * 4.0) as i32;
+    }
+
+    let w = BOSS_WIDTH;
+    let h = BOSS_HEIGHT;
+
+    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+
+    // Phase tint: violet -> orange -> red
+    let (r, g, b) = match boss.phase {
+        BossPhase::One => (110, 60, 200),
+        BossPhase::Two => (220, 120, 40),
+        BossPhase::Three => (230, 40, 40),
+    };
+
+    // Hull (stacked plates narrowing toward the bottom)
+    for i in 0..h {
+        let inset = (i * i) / (h * 2);
+        let shade = 1.0 - (i as f32 / h as f32) * 0.5;
+        canvas.set_draw_color(SdlColor::RGB(
+            (r as f32 * shade * 0.5) as u8,
+            (g as f32 * shade * 0.5) as u8,
+            (b as f32 * shade * 0.5) as u8,
+        ));
+        let _ = canvas.draw_line(Point::new(x + inset, y + i), Point::new(x + w - inset, y + i));
+    }
+
+    // Armor plating lines
+    canvas.set_draw_color(SdlColor::RGBA(r, g, b, 180));
+    for i in 1..6 {
+        let px = x + i * w / 6;
+        let _ = canvas.draw_line(Point::new(px, y + 4), Point::new(px, y + h / 3));
+    }
+    let _ = canvas.draw_line(Point::new(x, y), Point::new(x + w, y));
+
+    // Eye socket
+    let eye_cx = x + w / 2;
+    let eye_cy = y + h / 2;
+    let eye_radius = 26;
+    canvas.set_draw_color(SdlColor::RGB(15, 10, 20));
+    let _ = canvas.fill_rect(Rect::new(eye_cx - eye_radius - 6, eye_cy - eye_radius / 2 - 4, (eye_radius * 2 + 12) as u32, (eye_radius + 8) as u32));
+
+    // Pulsing eye
+    let pulse = (boss.frame_count as f32 * 0.1).sin() * 0.3 + 0.7;
+    for dy in -eye_radius / 2..=eye_radius / 2 {
+        for dx in -eye_radius..=eye_radius {
+            let dist = ((dx * dx) as f32 / (eye_radius * eye_radius) as f32
+                + (dy * dy) as f32 / ((eye_radius / 2) * (eye_radius / 2)) as f32).sqrt();
+            if dist <= 1.0 {
+                let glow = (1.0 - dist) * pulse;
+                canvas.set_draw_color(SdlColor::RGBA(255, (80.0 + glow * 175.0) as u8, (glow * 120.0) as u8, (120.0 + glow * 135.0) as u8));
+                let _ = canvas.draw_point(Point::new(eye_cx + dx, eye_cy + dy));
+            }
+        }
+    }
+
+    // Cannons
+    canvas.set_draw_color(SdlColor::RGB(70, 70, 80));
+    let _ = canvas.fill_rect(Rect::new(x + w / 2 - 8, y + h - 12, 16, 14));
+    let _ = canvas.fill_rect(Rect::new(x + 40, y + h / 2, 12, 20));
+    let _ = canvas.fill_rect(Rect::new(x + w - 52, y + h / 2, 12, 20));
+
+    // Hit flash
+    if boss.flash_timer > 0 {
+        let alpha = (boss.flash_timer * 25).min(200) as u8;
+        canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, alpha));
+        let _ = canvas.fill_rect(Rect::new(x, y, w as u32, h as u32));
+    }
+
+    // Shield shimmer while invulnerable (phase change)
+    if boss.invulnerable_timer > 0 && !boss.is_defeated() {
+        let alpha = ((boss.frame_count as f32 * 0.5).sin() * 60.0 + 80.0) as u8;
+        canvas.set_draw_color(SdlColor::RGBA(150, 220, 255, alpha));
+        for i in 0..3 {
+            let _ = canvas.draw_rect(Rect::new(x - 4 - i, y - 4 - i, (w + 8 + i * 2) as u32, (h + 8 + i * 2) as u32));
+        }
+    }
+
+    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+}
+
+/// Draw a glowing boss projectile
+fn draw_boss_projectile(canvas: &mut Canvas<Window>, projectile: &BossProjectile, frame_count: u64) {
+    let radius = PROJECTILE_SIZE / 2;
+    let cx = projectile.x as i32 + radius;
+    let cy = projectile.y as i32 + radius;
+    let flicker = if frame_count.is_multiple_of(4) { 30 } else { 0 };
+
+    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            let dist_sq = dx * dx + dy * dy;
+            if dist_sq <= radius * radius {
+                let factor = 1.0 - (dist_sq as f32).sqrt() / radius as f32;
+                canvas.set_draw_color(SdlColor::RGBA(
+                    255,
+                    (60.0 + factor * 195.0) as u8,
+                    (flicker as f32 + factor * 200.0) as u8,
+                    (100.0 + factor * 155.0) as u8,
+                ));
+                let _ = canvas.draw_point(Point::new(cx + dx, cy + dy));
+            }
+        }
+    }
+    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+}
+
+/// Draw the boss HP bar below the HUD
+fn draw_boss_hp_bar(canvas: &mut Canvas<Window>, boss: &Boss, font: &Font) {
+    let bar_width = 500;
+    let bar_height = 14;
+    let bar_x = WINDOW_WIDTH as i32 / 2 - bar_width / 2;
+    let bar_y = 62;
+
+    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+
+    // Background
+    canvas.set_draw_color(SdlColor::RGBA(40, 20, 30, 200));
+    let _ = canvas.fill_rect(Rect::new(bar_x, bar_y, bar_width as u32, bar_height as u32));
+
+    // Fill
+    let fill_width = (bar_width as f32 * boss.hp_fraction()) as u32;
+    if fill_width > 0 {
+        let color = if boss.flash_timer > 0 {
+            SdlColor::RGBA(255, 255, 255, 255)
+        } else {
+            match boss.phase {
+                BossPhase::One => SdlColor::RGBA(160, 90, 255, 230),
+                BossPhase::Two => SdlColor::RGBA(255, 150, 40, 230),
+                BossPhase::Three => SdlColor::RGBA(255, 50, 50, 230),
+            }
+        };
+        canvas.set_draw_color(color);
+        let _ = canvas.fill_rect(Rect::new(bar_x, bar_y, fill_width, bar_height as u32));
+    }
+
+    // Phase markers at 1/3 and 2/3
+    canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, 150));
+    for i in 1..3 {
+        let mx = bar_x + bar_width * i / 3;
+        let _ = canvas.draw_line(Point::new(mx, bar_y), Point::new(mx, bar_y + bar_height - 1));
+    }
+
+    // Border
+    canvas.set_draw_color(SdlColor::RGB(200, 200, 200));
+    let _ = canvas.draw_rect(Rect::new(bar_x, bar_y, bar_width as u32, bar_height as u32));
+    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+
+    // Label
+    let phase_number = match boss.phase {
+        BossPhase::One => 1,
+        BossPhase::Two => 2,
+        BossPhase::Three => 3,
+    };
+    let label = format!("GUARDIAN - PHASE {}", phase_number);
+    if let Ok(surface) = font.render(&label).blended(SdlColor::RGB(255, 200, 200)) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(
+                WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                bar_y + bar_height + 2,
+                surface.width(),
+                surface.height(),
+            );
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+}
+
 /// Draw swirling portal at center of screen with multi-stage animation
 /// Stages: Opening/Consuming (0-480), Closing (480-540), Flash (540-560), Fade (560-600)
 fn draw_portal(canvas: &mut Canvas<Window>, frame_count: u64, completion_timer: u64) {
@@ -2201,19 +2620,66 @@ fn draw_portal(canvas: &mut Canvas<Window>, frame_count: u64, completion_timer:
     canvas.set_blend_mode(sdl2::render::BlendMode::None);
 }
 
-fn render_hud(canvas: &mut Canvas<Window>, game: &Game, heart_texture: Option<&Texture>, font: &Font, fps: f32) {
-    // Draw score text
-    let score_text = format!("Score: {}", game.score);
-    if let Ok(surface) = font.render(&score_text).blended(SdlColor::RGB(255, 255, 255)) {
-        let texture_creator = canvas.texture_creator();
-        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
-            let target = Rect::new(10, 10, surface.width(), surface.height());
-            let _ = canvas.copy(&texture, None, Some(target));
-        };
+/// Versus: each player's goals on the left edge, on their side of the wall
+fn draw_versus_scoreboard(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
+    for (player, y) in [(1, WINDOW_HEIGHT as i32 / 2 - 110), (0, WINDOW_HEIGHT as i32 / 2 + 80)] {
+        let text = format!("P{}: {}", player + 1, game.goals[player]);
+        if let Ok(surface) = font.render(&text).blended(SdlColor::RGB(255, 255, 255)) {
+            let texture_creator = canvas.texture_creator();
+            if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                let target = Rect::new(10, y, surface.width(), surface.height());
+                let _ = canvas.copy(&texture, None, Some(target));
+            };
+        }
+    }
+}
+
+fn render_hud(canvas: &mut Canvas<Window>, game: &Game, menu: &Menu, heart_texture: Option<&Texture>, font: &Font, fps: f32) {
+    // Draw score text - goals in versus, both players in hot-seat (active one highlighted)
+    if game.mode == GameMode::Versus {
+        draw_versus_scoreboard(canvas, game, font);
+    } else if let Some(scores) = game.hot_seat_scores() {
+        for (i, score) in scores.iter().enumerate() {
+            let active = i == game.player;
+            let text = format!("{}P{}: {}", if active { "> " } else { "  " }, i + 1, score);
+            let color = if active { SdlColor::RGB(255, 255, 100) } else { SdlColor::RGB(150, 150, 150) };
+            if let Ok(surface) = font.render(&text).blended(color) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(10, 10 + i as i32 * 30, surface.width(), surface.height());
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+        }
+    } else {
+        let score_text = format!("Score: {}", game.score);
+        if let Ok(surface) = font.render(&score_text).blended(SdlColor::RGB(255, 255, 255)) {
+            let texture_creator = canvas.texture_creator();
+            if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                let target = Rect::new(10, 10, surface.width(), surface.height());
+                let _ = canvas.copy(&texture, None, Some(target));
+            };
+        }
+
+        // Co-op: team score above, each player's share below
+        if game.mode == GameMode::CoOp {
+            let text = format!("P1: {}  P2: {}", game.player_scores[0], game.player_scores[1]);
+            if let Ok(surface) = font.render(&text).blended(SdlColor::RGB(150, 150, 150)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(10, 40, surface.width(), surface.height());
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+        }
     }
     
-    // Draw lives as hearts
-    if let Some(heart_tex) = heart_texture {
+    // Draw running timer instead of lives in time attack
+    if let Some(ref time_attack) = game.time_attack {
+        draw_time_attack_timer(canvas, time_attack, font);
+    } else if game.mode == GameMode::Versus {
+        // No lives in versus - a lost ball is a goal for the opponent
+    } else if let Some(heart_tex) = heart_texture {
         // Use heart texture
         let heart_size = 20;
         for i in 0..game.lives {
@@ -2253,6 +2719,16 @@ fn render_hud(canvas: &mut Canvas<Window>, game: &Game, heart_texture: Option<&T
     // Level
     let level_text = if game.is_test_mode {
         "TEST".to_string()
+    } else if let Some(ref stage) = game.bonus_stage {
+        format!("BONUS STAGE - {} - {}s", stage.def.level.name, stage.seconds_left())
+    } else if game.mode == GameMode::Versus {
+        format!("VERSUS - first to {}", VERSUS_GOALS)
+    } else if let Some(ref daily) = game.daily {
+        format!("Daily {} - Level {}/{}", daily.date, game.current_level, DAILY_LEVELS)
+    } else if let Some(def) = game.level_def() {
+        format!("Level {} - {}", game.current_level, def.name)
+    } else if is_boss_level(game.current_level) {
+        format!("Level {} - BOSS", game.current_level)
     } else {
         format!("Level {}", game.current_level)
     };
@@ -2293,6 +2769,18 @@ fn render_hud(canvas: &mut Canvas<Window>, game: &Game, heart_texture: Option<&T
         };
     }
     
+    // Draw active effects strip (above MAX SPEED), one row per paddle
+    let paddles = std::iter::once(&game.paddle).chain(game.paddle2.iter());
+    for (index, paddle) in paddles.enumerate() {
+        let label = if game.paddle2.is_some() { Some(format!("P{}", index + 1)) } else { None };
+        draw_effects_strip(canvas, &paddle.effects, label, WINDOW_HEIGHT as i32 - 85 - index as i32 * 45, font);
+    }
+
+    // Draw combo meter
+    if game.combo > 0 {
+        draw_combo_meter(canvas, game, font);
+    }
+
     // Draw FPS counter (bottom-right)
     let fps_text = format!("FPS: {:.0}", fps);
     if let Ok(surface) = font.render(&fps_text).blended(SdlColor::RGB(200, 200, 200)) {
@@ -2308,18 +2796,44 @@ fn render_hud(canvas: &mut Canvas<Window>, game: &Game, heart_texture: Option<&T
         };
     }
     
-    // Draw GRAVITY MODE indicator (if active)
-    if game.gravity_mode {
-        let gravity_text = "GRAVITY MODE";
+    // Draw autopilot indicator (above the FPS counter)
+    if let Some(difficulty) = menu.autopilot {
+        let autopilot_text = format!("AUTOPILOT ({})", difficulty.name());
+        if let Ok(surface) = font.render(&autopilot_text).blended(SdlColor::RGB(100, 200, 255)) {
+            let texture_creator = canvas.texture_creator();
+            if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                let target = Rect::new(
+                    WINDOW_WIDTH as i32 - surface.width() as i32 - 10,
+                    WINDOW_HEIGHT as i32 - surface.height() as i32 * 2 - 15,
+                    surface.width(),
+                    surface.height()
+                );
+                let _ = canvas.copy(&texture, None, Some(target));
+            };
+        }
+    }
+    
+    // Draw boss HP bar
+    if let Some(ref boss) = game.boss {
+        draw_boss_hp_bar(canvas, boss, font);
+    }
+
+    // Draw active modifiers indicator (if any)
+    if !game.modifiers.is_empty() {
+        let modifiers_text = format!(
+            "{} (x{:.2})",
+            game.modifiers.names().join(" + ").to_uppercase(),
+            game.modifiers.score_multiplier()
+        );
         // Pulsing orange/red color to make it stand out
         let pulse = (game.frame_count as f32 * 0.1).sin() * 0.3 + 0.7;
-        let gravity_color = SdlColor::RGB(
+        let modifiers_color = SdlColor::RGB(
             (255.0 * pulse) as u8,
             (100.0 * pulse) as u8,
             0,
         );
         
-        if let Ok(surface) = font.render(gravity_text).blended(gravity_color) {
+        if let Ok(surface) = font.render(&modifiers_text).blended(modifiers_color) {
             let texture_creator = canvas.texture_creator();
             if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
                 // Position below level indicator
@@ -2335,34 +2849,150 @@ fn render_hud(canvas: &mut Canvas<Window>, game: &Game, heart_texture: Option<&T
     }
 }
 
-/// Draw a particle (glass shard)
-fn draw_particle(canvas: &mut Canvas<Window>, particle: &Particle) {
-    let alpha = particle.alpha();
-    
-    // Draw rotated rectangle for glass shard
-    let half_size = particle.size / 2;
-    let angle = particle.rotation.to_radians();
-    
-    canvas.set_draw_color(SdlColor::RGBA(
-        particle.color.r,
-        particle.color.g,
-        particle.color.b,
-        alpha,
-    ));
-    
-    // Simple diamond/shard shape
-    for dx in -half_size..=half_size {
-        for dy in -half_size..=half_size {
-            if dx.abs() + dy.abs() <= half_size {
-                let rotated_x = (dx as f32 * angle.cos() - dy as f32 * angle.sin()) as i32;
-                let rotated_y = (dx as f32 * angle.sin() + dy as f32 * angle.cos()) as i32;
-                let _ = canvas.draw_point(Point::new(
-                    particle.x as i32 + rotated_x,
-                    particle.y as i32 + rotated_y,
-                ));
-            }
-        }
-    }
+/// Draw the time attack clock (TOP RIGHT), with a red flash after a penalty
+fn draw_time_attack_timer(canvas: &mut Canvas<Window>, time_attack: &TimeAttack, font: &Font) {
+    let timer_text = format!("TIME {}", format_time(time_attack.elapsed_frames));
+    if let Ok(surface) = font.render(&timer_text).blended(SdlColor::RGB(255, 255, 255)) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(WINDOW_WIDTH as i32 - surface.width() as i32 - 10, 10, surface.width(), surface.height());
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+
+    if time_attack.penalty_flash() {
+        let penalty_text = format!("+{}s", LOST_BALL_PENALTY_FRAMES / 60);
+        if let Ok(surface) = font.render(&penalty_text).blended(SdlColor::RGB(255, 60, 60)) {
+            let texture_creator = canvas.texture_creator();
+            if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                let target = Rect::new(WINDOW_WIDTH as i32 - surface.width() as i32 - 10, 40, surface.width(), surface.height());
+                let _ = canvas.copy(&texture, None, Some(target));
+            };
+        }
+    }
+}
+
+/// Draw a floating score popup, tinted by combo multiplier
+fn draw_score_popup(canvas: &mut Canvas<Window>, popup: &ScorePopup, font: &Font) {
+    let color = match popup.multiplier {
+        1 => SdlColor::RGB(255, 255, 255),
+        2 => SdlColor::RGB(255, 240, 80),
+        3 => SdlColor::RGB(255, 160, 40),
+        _ => SdlColor::RGB(255, 60, 60),
+    };
+
+    if let Ok(surface) = font.render(&popup.text()).blended(color) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(mut texture) = texture_creator.create_texture_from_surface(&surface) {
+            texture.set_alpha_mod(popup.alpha());
+            let target = Rect::new(
+                popup.x as i32 - surface.width() as i32 / 2,
+                popup.y as i32 - surface.height() as i32 / 2,
+                surface.width(),
+                surface.height(),
+            );
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+}
+
+/// Draw the combo counter with progress towards the next multiplier (BOTTOM LEFT)
+fn draw_combo_meter(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
+    let multiplier = game.combo_multiplier();
+    let combo_text = format!("COMBO {} x{}", game.combo, multiplier);
+    let color = if multiplier > 1 {
+        SdlColor::RGB(255, 200, 60)
+    } else {
+        SdlColor::RGB(220, 220, 220)
+    };
+
+    let y = WINDOW_HEIGHT as i32 - 80;
+    if let Ok(surface) = font.render(&combo_text).blended(color) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(10, y, surface.width(), surface.height());
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+
+    // Progress bar - full at max multiplier
+    let bar_width = 120;
+    let fill = if multiplier >= 5 {
+        bar_width
+    } else {
+        (game.combo % 5) as i32 * bar_width / 5
+    };
+    canvas.set_draw_color(SdlColor::RGB(60, 60, 60));
+    let _ = canvas.fill_rect(Rect::new(10, y + 28, bar_width as u32, 6));
+    if fill > 0 {
+        canvas.set_draw_color(color);
+        let _ = canvas.fill_rect(Rect::new(10, y + 28, fill as u32, 6));
+    }
+}
+
+/// Draw a particle (glass shard)
+/// Dotted launch trajectory, fading toward its end
+fn draw_aim_preview(canvas: &mut Canvas<Window>, points: &[(f32, f32)]) {
+    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+    for (i, &(x, y)) in points.iter().enumerate() {
+        let fade = 1.0 - i as f32 / points.len() as f32;
+        canvas.set_draw_color(SdlColor::RGBA(255, 255, 255, (40.0 + fade * 200.0) as u8));
+        let _ = canvas.fill_rect(Rect::new(x as i32 - 2, y as i32 - 2, 4, 4));
+    }
+    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+}
+
+/// Expanding explosion ring: a bright front with a fainter trailing edge
+fn draw_shockwave(canvas: &mut Canvas<Window>, shockwave: &Shockwave) {
+    let alpha = shockwave.alpha();
+    let segments = 64;
+
+    for (offset, color) in [(0.0, SdlColor::RGBA(255, 230, 180, alpha)), (-4.0, SdlColor::RGBA(255, 120, 40, alpha / 2))] {
+        let radius = shockwave.radius + offset;
+        if radius <= 0.0 {
+            continue;
+        }
+        canvas.set_draw_color(color);
+        let points: Vec<Point> = (0..=segments)
+            .map(|i| {
+                let angle = i as f32 / segments as f32 * std::f32::consts::PI * 2.0;
+                Point::new(
+                    (shockwave.x + angle.cos() * radius) as i32,
+                    (shockwave.y + angle.sin() * radius) as i32,
+                )
+            })
+            .collect();
+        let _ = canvas.draw_lines(points.as_slice());
+    }
+}
+
+fn draw_particle(canvas: &mut Canvas<Window>, particle: &Particle) {
+    let alpha = particle.alpha();
+    
+    // Draw rotated rectangle for glass shard
+    let half_size = particle.size / 2;
+    let angle = particle.rotation.to_radians();
+    
+    canvas.set_draw_color(SdlColor::RGBA(
+        particle.color.r,
+        particle.color.g,
+        particle.color.b,
+        alpha,
+    ));
+    
+    // Simple diamond/shard shape
+    for dx in -half_size..=half_size {
+        for dy in -half_size..=half_size {
+            if dx.abs() + dy.abs() <= half_size {
+                let rotated_x = (dx as f32 * angle.cos() - dy as f32 * angle.sin()) as i32;
+                let rotated_y = (dx as f32 * angle.sin() + dy as f32 * angle.cos()) as i32;
+                let _ = canvas.draw_point(Point::new(
+                    particle.x as i32 + rotated_x,
+                    particle.y as i32 + rotated_y,
+                ));
+            }
+        }
+    }
 }
 
 /// Draw animated penguin with jetpack stealing a heart
@@ -2397,8 +3027,16 @@ fn draw_penguin(canvas: &mut Canvas<Window>, penguin: &Penguin) {
     }
 
     
+    // Hit: flash white every few frames
+    let hit = penguin.state == PenguinState::Hit;
+    let dark = if hit && (penguin.frame_count / 3).is_multiple_of(2) {
+        SdlColor::RGB(255, 255, 255)
+    } else {
+        SdlColor::RGB(30, 30, 40)
+    };
+
     // Body (black oval)
-    canvas.set_draw_color(SdlColor::RGB(30, 30, 40));
+    canvas.set_draw_color(dark);
     let _ = canvas.fill_rect(Rect::new(x, body_y + 8, 25, 30));
     
     // Belly (white oval)
@@ -2411,13 +3049,29 @@ fn draw_penguin(canvas: &mut Canvas<Window>, penguin: &Penguin) {
     let _ = canvas.fill_rect(Rect::new(x + 15, body_y + 20, 5, 15));
     
     // Head (black circle)
-    canvas.set_draw_color(SdlColor::RGB(30, 30, 40));
+    canvas.set_draw_color(dark);
     let _ = canvas.fill_rect(Rect::new(x + 3, body_y, 19, 18));
     
-    // Eyes (white dots)
-    canvas.set_draw_color(SdlColor::RGB(255, 255, 255));
-    let _ = canvas.fill_rect(Rect::new(x + 8, body_y + 5, 3, 3));
-    let _ = canvas.fill_rect(Rect::new(x + 14, body_y + 5, 3, 3));
+    if hit {
+        // Dazed eyes (red crosses) and stars circling the head
+        canvas.set_draw_color(SdlColor::RGB(255, 60, 60));
+        for eye_x in [x + 8, x + 14] {
+            let _ = canvas.draw_line(Point::new(eye_x, body_y + 4), Point::new(eye_x + 3, body_y + 7));
+            let _ = canvas.draw_line(Point::new(eye_x + 3, body_y + 4), Point::new(eye_x, body_y + 7));
+        }
+        canvas.set_draw_color(SdlColor::RGB(255, 230, 80));
+        for i in 0..3 {
+            let angle = penguin.frame_count as f32 * 0.3 + i as f32 * std::f32::consts::PI * 2.0 / 3.0;
+            let star_x = x + 12 + (angle.cos() * 14.0) as i32;
+            let star_y = body_y - 6 + (angle.sin() * 4.0) as i32;
+            let _ = canvas.fill_rect(Rect::new(star_x - 1, star_y - 1, 3, 3));
+        }
+    } else {
+        // Eyes (white dots)
+        canvas.set_draw_color(SdlColor::RGB(255, 255, 255));
+        let _ = canvas.fill_rect(Rect::new(x + 8, body_y + 5, 3, 3));
+        let _ = canvas.fill_rect(Rect::new(x + 14, body_y + 5, 3, 3));
+    }
     
     // Beak (orange triangle)
     canvas.set_draw_color(SdlColor::RGB(255, 140, 0));
@@ -2428,8 +3082,8 @@ fn draw_penguin(canvas: &mut Canvas<Window>, penguin: &Penguin) {
     let _ = canvas.fill_rect(Rect::new(x + 7, body_y + 38, 5, 2));
     let _ = canvas.fill_rect(Rect::new(x + 13, body_y + 38, 5, 2));
     
-    // Draw heart if grabbing or running away
-    if matches!(penguin.state, PenguinState::Grabbing | PenguinState::RunningAway) {
+    // Draw heart if grabbing or running away with it
+    if penguin.state == PenguinState::Grabbing || (penguin.state == PenguinState::RunningAway && penguin.has_heart) {
         let heart_x = if penguin.state == PenguinState::Grabbing {
             x + 20 // Heart in grabbing position
         } else {
@@ -2531,7 +3185,7 @@ fn render_pause_menu(canvas: &mut Canvas<Window>, menu: &Menu, menu_background:
                 if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
                     let target = Rect::new(
                         WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
-                        WINDOW_HEIGHT as i32 / 2 - 180,
+                        WINDOW_HEIGHT as i32 / 2 - 210,
                         surface.width(),
                         surface.height(),
                     );
@@ -2541,7 +3195,9 @@ fn render_pause_menu(canvas: &mut Canvas<Window>, menu: &Menu, menu_background:
             
             render_button(canvas, &menu.resume_button, font);
             render_button(canvas, &menu.restart_button, font);
-            render_button(canvas, &menu.gravity_mode_button, font);
+            render_button(canvas, &menu.modes_button, font);
+            render_button(canvas, &menu.campaigns_button, font);
+            render_button(canvas, &menu.modifiers_button, font);
             render_button(canvas, &menu.level_editor_button, font);
             render_button(canvas, &menu.settings_button, font);
             render_button(canvas, &menu.quit_button, font);
@@ -2585,6 +3241,10 @@ fn render_pause_menu(canvas: &mut Canvas<Window>, menu: &Menu, menu_background:
             render_volume_slider(canvas, &menu.sfx_slider, font);
             render_button(canvas, &menu.fullscreen_button, font);
             render_button(canvas, &menu.vsync_button, font);
+            render_button(canvas, &menu.autopilot_button, font);
+            render_button(canvas, &menu.auto_launch_button, font);
+            render_button(canvas, &menu.heart_rescue_button, font);
+            render_button(canvas, &menu.bonus_stage_button, font);
             
             // Resolution selection header
             let center_x = WINDOW_WIDTH as i32 / 2;
@@ -2666,7 +3326,208 @@ fn render_pause_menu(canvas: &mut Canvas<Window>, menu: &Menu, menu_background:
             
             render_button(canvas, &menu.back_button, font);
         }
+        MenuState::Modes => {
+            if let Ok(surface) = font.render("GAME MODES").blended(SdlColor::RGB(255, 255, 255)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        WINDOW_HEIGHT as i32 / 2 - 290,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.campaign_button, font);
+            render_button(canvas, &menu.time_attack_button, font);
+
+            // Short description of time attack
+            let hint = format!("Clear {} levels as fast as you can", TIME_ATTACK_LEVELS);
+            if let Ok(surface) = font.render(&hint).blended(SdlColor::RGB(150, 150, 150)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        menu.time_attack_button.rect.bottom() + 10,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.daily_button, font);
+
+            let hint = format!("{} levels picked by today's date - one counted run a day", DAILY_LEVELS);
+            if let Ok(surface) = font.render(&hint).blended(SdlColor::RGB(150, 150, 150)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        menu.daily_button.rect.bottom() + 10,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.hot_seat_button, font);
+
+            if let Ok(surface) = font.render("Take turns - a lost life passes control").blended(SdlColor::RGB(150, 150, 150)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        menu.hot_seat_button.rect.bottom() + 10,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.co_op_button, font);
+
+            if let Ok(surface) = font.render("Two paddles, one team - P1 arrows, P2 A/D or mouse").blended(SdlColor::RGB(150, 150, 150)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        menu.co_op_button.rect.bottom() + 10,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.versus_button, font);
+
+            if let Ok(surface) = font.render("Head to head - P2 defends the top edge").blended(SdlColor::RGB(150, 150, 150)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        menu.versus_button.rect.bottom() + 10,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.modes_back_button, font);
+        }
+        MenuState::Modifiers => {
+            if let Ok(surface) = font.render("MODIFIERS").blended(SdlColor::RGB(255, 255, 255)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        WINDOW_HEIGHT as i32 / 2 - 220,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            for (btn, kind) in menu.modifier_buttons.iter().zip(ModifierKind::ALL) {
+                // Highlight active modifiers
+                if menu.modifiers.contains(&kind) {
+                    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+                    canvas.set_draw_color(SdlColor::RGBA(255, 120, 0, 100));
+                    let _ = canvas.fill_rect(btn.rect);
+                    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+                }
+                render_button(canvas, btn, font);
+            }
+
+            // Combined score multiplier, and when the selection takes effect
+            let mut summary = format!("Score multiplier: x{:.2}", selection_multiplier(&menu.modifiers));
+            if menu.game_started {
+                summary.push_str(" - applies after restart");
+            }
+            if let Ok(surface) = font.render(&summary).blended(SdlColor::RGB(255, 200, 60)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        menu.modifiers_back_button.rect.y() - 45,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.modifiers_back_button, font);
+        }
+        MenuState::Campaigns => {
+            if let Ok(surface) = font.render("CAMPAIGNS").blended(SdlColor::RGB(255, 255, 255)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        WINDOW_HEIGHT as i32 / 2 - 220,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
 
+            for (i, btn) in menu.pack_buttons.iter().enumerate() {
+                // Highlight the selected campaign
+                if i == menu.selected_pack {
+                    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+                    canvas.set_draw_color(SdlColor::RGBA(255, 120, 0, 100));
+                    let _ = canvas.fill_rect(btn.rect);
+                    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+                }
+                render_button(canvas, btn, font);
+            }
+
+            // Description of the selected campaign, and what picking another one does
+            let mut summary = crate::packs::current().description.clone();
+            if menu.game_started {
+                summary.push_str(" - picking another campaign ends this run");
+            }
+            if let Ok(surface) = font.render(&summary).blended(SdlColor::RGB(255, 200, 60)) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        menu.campaigns_back_button.rect.y() - 45,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+
+            render_button(canvas, &menu.campaigns_back_button, font);
+
+            // Packs that failed to load, with the reason
+            for (i, broken) in crate::packs::packs().broken.iter().enumerate() {
+                let line = format!("{}: {}", broken.id, broken.error);
+                if let Ok(surface) = font.render(&line).blended(SdlColor::RGB(255, 80, 80)) {
+                    let texture_creator = canvas.texture_creator();
+                    if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                        let target = Rect::new(
+                            WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                            menu.campaigns_back_button.rect.bottom() + 15 + i as i32 * 30,
+                            surface.width(),
+                            surface.height(),
+                        );
+                        let _ = canvas.copy(&texture, None, Some(target));
+                    };
+                }
+            }
+        }
     }
 }
 
@@ -2699,8 +3560,27 @@ fn render_game_over_menu(canvas: &mut Canvas<Window>, game: &Game, font: &Font)
         };
     }
     
-    // Score
-    let score_text = format!("Final Score: {}", game.score);
+    // Score - both players and the winner in hot-seat
+    let score_text = match game.hot_seat_scores() {
+        Some([p1, p2]) => {
+            let winner = match p1.cmp(&p2) {
+                std::cmp::Ordering::Greater => "Player 1 wins!",
+                std::cmp::Ordering::Less => "Player 2 wins!",
+                std::cmp::Ordering::Equal => "It's a draw!",
+            };
+            format!("Player 1: {}   Player 2: {} - {}", p1, p2, winner)
+        }
+        None if game.mode == GameMode::CoOp => format!(
+            "Team Score: {}   (P1: {}  P2: {})",
+            game.score, game.player_scores[0], game.player_scores[1]
+        ),
+        None if game.mode == GameMode::Versus => {
+            let [p1, p2] = game.goals;
+            let winner = if p1 > p2 { 1 } else { 2 };
+            format!("Player {} wins {} - {}!", winner, p1.max(p2), p1.min(p2))
+        }
+        None => format!("Final Score: {}", game.score),
+    };
     if let Ok(surface) = font.render(&score_text).blended(SdlColor::RGB(255, 255, 255)) {
         let texture_creator = canvas.texture_creator();
         if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
@@ -2728,6 +3608,84 @@ fn render_game_over_menu(canvas: &mut Canvas<Window>, game: &Game, font: &Font)
             let _ = canvas.copy(&texture, None, Some(target));
         };
     }
+
+    if let Some(ref daily) = game.daily {
+        render_daily_summary(canvas, daily, game.score, WINDOW_HEIGHT as i32 / 2 + 70, font);
+    }
+}
+
+/// Where a daily run stands: counted or practice, and the best day so far
+fn render_daily_summary(canvas: &mut Canvas<Window>, daily: &DailyChallenge, score: u32, y: i32, font: &Font) {
+    let text = match (&daily.counted, &daily.best) {
+        (Some(counted), _) => format!("Practice run - today's counted score is {}", counted.score),
+        (None, Some(best)) if best.score >= score => {
+            format!("Counted as today's run - best day {} on {}", best.score, best.date)
+        }
+        (None, _) => "Counted as today's run - your best day so far!".to_string(),
+    };
+    if let Ok(surface) = font.render(&text).blended(SdlColor::RGB(150, 200, 255)) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(
+                WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                y,
+                surface.width(),
+                surface.height(),
+            );
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+}
+
+/// Hot-seat interstitial shown when control passes to the other player
+fn render_player_ready(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
+    // Semi-transparent overlay
+    canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+    canvas.set_draw_color(SdlColor::RGBA(0, 0, 0, 180));
+    canvas.fill_rect(None).unwrap();
+    canvas.set_blend_mode(sdl2::render::BlendMode::None);
+
+    let title = format!("PLAYER {} READY", game.player + 1);
+    if let Ok(surface) = font.render(&title).blended(SdlColor::RGB(255, 255, 100)) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(
+                WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                WINDOW_HEIGHT as i32 / 2 - 80,
+                surface.width(),
+                surface.height(),
+            );
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+
+    let info = format!("Level {} - Lives {} - Score {}", game.current_level, game.lives, game.score);
+    if let Ok(surface) = font.render(&info).blended(SdlColor::RGB(255, 255, 255)) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(
+                WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                WINDOW_HEIGHT as i32 / 2 - 20,
+                surface.width(),
+                surface.height(),
+            );
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+
+    let prompt = "Click or press ENTER to start";
+    if let Ok(surface) = font.render(prompt).blended(SdlColor::RGB(200, 200, 200)) {
+        let texture_creator = canvas.texture_creator();
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(
+                WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                WINDOW_HEIGHT as i32 / 2 + 40,
+                surface.width(),
+                surface.height(),
+            );
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
 }
 
 fn render_level_transition(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
@@ -2744,22 +3702,56 @@ fn render_level_transition(canvas: &mut Canvas<Window>, game: &Game, font: &Font
         if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
             let target = Rect::new(
                 WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
-                WINDOW_HEIGHT as i32 / 2 - 100,
+                70,
                 surface.width(),
                 surface.height(),
             );
             let _ = canvas.copy(&texture, None, Some(target));
         };
     }
-    
-    // Score
-    let score_text = format!("Score: {}", game.score);
+
+    let results = &game.results;
+    let stats = &results.stats;
+    let stat_color = SdlColor::RGB(200, 200, 200);
+    let bonus_color = SdlColor::RGB(255, 200, 60);
+
+    // Level statistics
+    let stat_lines = [
+        ("Time", format!("{}:{:02}", stats.seconds() / 60, stats.seconds() % 60)),
+        ("Blocks (ball / rocket / explosion)", format!(
+            "{} / {} / {}",
+            stats.blocks_by_ball, stats.blocks_by_rocket, stats.blocks_by_explosion
+        )),
+        ("Paddle hits", stats.paddle_hits.to_string()),
+        ("Bonuses caught / missed", format!("{} / {}", stats.bonuses_caught, stats.bonuses_missed)),
+        ("Lives lost / hearts rescued", format!("{} / {}", stats.lives_lost, stats.hearts_rescued)),
+        ("Top speed", format!("{} px/s", stats.top_speed as i32)),
+        ("Longest combo", results.best_combo.to_string()),
+    ];
+    let mut y = 130;
+    for (label, value) in &stat_lines {
+        draw_results_line(canvas, font, label, value, y, stat_color);
+        y += 30;
+    }
+
+    // Bonus breakdown, counting up one line after another
+    y += 15;
+    for (i, (label, _)) in results.bonus_lines().iter().enumerate() {
+        let value = format!("+{}", results.displayed(i));
+        draw_results_line(canvas, font, label, &value, y, bonus_color);
+        y += 30;
+    }
+
+    // Score - counts up together with the bonuses
+    y += 15;
+    let shown_score = game.score.saturating_sub(results.total()) + results.displayed_total();
+    let score_text = format!("Score: {}", shown_score);
     if let Ok(surface) = font.render(&score_text).blended(SdlColor::RGB(255, 255, 255)) {
         let texture_creator = canvas.texture_creator();
         if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
             let target = Rect::new(
                 WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
-                WINDOW_HEIGHT as i32 / 2 - 40,
+                y,
                 surface.width(),
                 surface.height(),
             );
@@ -2767,14 +3759,46 @@ fn render_level_transition(canvas: &mut Canvas<Window>, game: &Game, font: &Font
         };
     }
     
+    // Time attack split against personal best
+    if let Some(ref time_attack) = game.time_attack {
+        if let Some(&split) = time_attack.splits.last() {
+            let (split_text, color) = match time_attack.last_split_delta() {
+                Some(delta) => (
+                    format!("Split: {} ({})", format_time(split), format_delta(delta)),
+                    if delta <= 0 { SdlColor::RGB(0, 255, 100) } else { SdlColor::RGB(255, 80, 80) },
+                ),
+                None => (format!("Split: {}", format_time(split)), SdlColor::RGB(255, 255, 255)),
+            };
+            if let Ok(surface) = font.render(&split_text).blended(color) {
+                let texture_creator = canvas.texture_creator();
+                if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+                    let target = Rect::new(
+                        WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
+                        y + 35,
+                        surface.width(),
+                        surface.height(),
+                    );
+                    let _ = canvas.copy(&texture, None, Some(target));
+                };
+            }
+            y += 35;
+        }
+    }
+    
     // Click to continue prompt
-    let prompt_text = "Click to start next level";
+    let prompt_text = if !results.is_revealed() {
+        "Click to skip"
+    } else if game.is_warp_pending() {
+        "Click to warp to the BONUS STAGE"
+    } else {
+        "Click to start next level"
+    };
     if let Ok(surface) = font.render(prompt_text).blended(SdlColor::RGB(255, 255, 100)) {
         let texture_creator = canvas.texture_creator();
         if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
             let target = Rect::new(
                 WINDOW_WIDTH as i32 / 2 - surface.width() as i32 / 2,
-                WINDOW_HEIGHT as i32 / 2 + 40,
+                y + 50,
                 surface.width(),
                 surface.height(),
             );
@@ -2783,6 +3807,26 @@ fn render_level_transition(canvas: &mut Canvas<Window>, game: &Game, font: &Font
     }
 }
 
+/// One row of the results screen: label on the left, value on the right
+fn draw_results_line(canvas: &mut Canvas<Window>, font: &Font, label: &str, value: &str, y: i32, color: SdlColor) {
+    let left = WINDOW_WIDTH as i32 / 2 - 300;
+    let right = WINDOW_WIDTH as i32 / 2 + 300;
+    let texture_creator = canvas.texture_creator();
+
+    if let Ok(surface) = font.render(label).blended(color) {
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(left, y, surface.width(), surface.height());
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+    if let Ok(surface) = font.render(value).blended(color) {
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let target = Rect::new(right - surface.width() as i32, y, surface.width(), surface.height());
+            let _ = canvas.copy(&texture, None, Some(target));
+        };
+    }
+}
+
 fn render_victory_menu(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
     // Semi-transparent overlay
     canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
@@ -2805,7 +3849,11 @@ fn render_victory_menu(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
     }
     
     // Subtitle
-    let subtitle = "Campaign Complete!";
+    let subtitle = match game.mode {
+        GameMode::TimeAttack => "Time Attack Complete!",
+        GameMode::Daily => "Daily Challenge Complete!",
+        _ => "Campaign Complete!",
+    };
     if let Ok(surface) = font.render(subtitle).blended(SdlColor::RGB(0, 255, 0)) {
         let texture_creator = canvas.texture_creator();
         if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
@@ -2819,8 +3867,21 @@ fn render_victory_menu(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
         };
     }
     
-    // Score
-    let score_text = format!("Final Score: {}", game.score);
+    // Score - or the final time in time attack
+    let score_text = match game.time_attack {
+        Some(ref time_attack) if time_attack.new_best_total => {
+            format!("Time: {} - New personal best!", format_time(time_attack.elapsed_frames))
+        }
+        Some(ref time_attack) => match time_attack.best.total {
+            Some(best) => format!("Time: {} (best {})", format_time(time_attack.elapsed_frames), format_time(best)),
+            None => format!("Time: {}", format_time(time_attack.elapsed_frames)),
+        },
+        None if game.mode == GameMode::CoOp => format!(
+            "Team Score: {}   (P1: {}  P2: {})",
+            game.score, game.player_scores[0], game.player_scores[1]
+        ),
+        None => format!("Final Score: {}", game.score),
+    };
     if let Ok(surface) = font.render(&score_text).blended(SdlColor::RGB(255, 255, 255)) {
         let texture_creator = canvas.texture_creator();
         if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
@@ -2835,7 +3896,11 @@ fn render_victory_menu(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
     }
     
     // Instructions
-    let inst_text = "Press ENTER for Infinite Mode";
+    let inst_text = if matches!(game.mode, GameMode::TimeAttack | GameMode::Daily) {
+        "Press ENTER to try again"
+    } else {
+        "Press ENTER for Infinite Mode"
+    };
     if let Ok(surface) = font.render(inst_text).blended(SdlColor::RGB(255, 215, 0)) {
         let texture_creator = canvas.texture_creator();
         if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
@@ -2863,6 +3928,10 @@ fn render_victory_menu(canvas: &mut Canvas<Window>, game: &Game, font: &Font) {
             let _ = canvas.copy(&texture, None, Some(target));
         };
     }
+
+    if let Some(ref daily) = game.daily {
+        render_daily_summary(canvas, daily, game.score, WINDOW_HEIGHT as i32 / 2 + 130, font);
+    }
 }
 
 
@@ -2888,13 +3957,14 @@ pub fn render_editor(
     canvas.fill_rect(None).ok();
     canvas.set_blend_mode(sdl2::render::BlendMode::None);
 
-    let total_blocks_width = BLOCK_COLS as i32 * BLOCK_WIDTH;
-    let offset_x = (WINDOW_WIDTH as i32 - total_blocks_width) / 2;
+    let layout = editor.layout;
+    let total_blocks_width = layout.width();
+    let offset_x = layout.offset_x();
 
     // Draw grid lines
     canvas.set_draw_color(SdlColor::RGBA(80, 80, 100, 200));
-    for row in 0..=BLOCK_ROWS {
-        let y = BLOCK_OFFSET_Y + row as i32 * BLOCK_HEIGHT;
+    for row in 0..=layout.rows {
+        let y = BLOCK_OFFSET_Y + row as i32 * layout.block_height;
         canvas
             .draw_line(
                 Point::new(offset_x, y),
@@ -2902,12 +3972,12 @@ pub fn render_editor(
             )
             .ok();
     }
-    for col in 0..=BLOCK_COLS {
-        let x = offset_x + col as i32 * BLOCK_WIDTH;
+    for col in 0..=layout.cols {
+        let x = offset_x + col as i32 * layout.block_width;
         canvas
             .draw_line(
                 Point::new(x, BLOCK_OFFSET_Y),
-                Point::new(x, BLOCK_OFFSET_Y + (BLOCK_ROWS as i32 * BLOCK_HEIGHT)),
+                Point::new(x, BLOCK_OFFSET_Y + layout.height()),
             )
             .ok();
     }
@@ -2915,7 +3985,7 @@ pub fn render_editor(
     // Draw placed blocks using the shared rendering function for consistency
     for block in &editor.blocks {
         if block.active {
-            draw_block_with_gradient(canvas, block, cache, editor.frame_count);
+            draw_block_with_gradient(canvas, block, &cache.blocks, editor.frame_count);
         }
     }
 
@@ -3128,7 +4198,7 @@ pub fn render_editor(
         }
         
         // Instructions
-        let inst_text = "Click pattern to load | ESC to cancel";
+        let inst_text = "Click pattern to load | U: upgrade .txt to v2 | ESC to cancel";
         let inst_surface = font.render(inst_text).blended(SdlColor::RGB(180, 180, 180)).ok();
         if let Some(surface) = inst_surface {
             if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
@@ -3184,6 +4254,25 @@ pub fn render_editor(
         }
     }
 
+    // Draw grid info
+    let grid_text = format!("Grid {}x{} (G to change)", editor.layout.cols, editor.layout.rows);
+    let surface = font
+        .render(&grid_text)
+        .blended(SdlColor::RGB(150, 150, 150))
+        .ok();
+    if let Some(surface) = surface {
+        if let Ok(texture) = texture_creator.create_texture_from_surface(&surface) {
+            let query = texture.query();
+            let target = Rect::new(
+                WINDOW_WIDTH as i32 - query.width as i32 - 10,
+                WINDOW_HEIGHT as i32 - 175,
+                query.width,
+                query.height,
+            );
+            canvas.copy(&texture, None, Some(target)).ok();
+        }
+    }
+
     // Draw message if present
     if !editor.message.is_empty() {
         let surface = font
@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use crate::bonus_stage::PortalConfig;
+use crate::editor::PatternData;
+use crate::game::{Game, GameState, SoundEffect};
+use crate::modifiers::ModifierKind;
+use crate::{packs, rng};
+
+/// Current replay file version
+const REPLAY_FORMAT_VERSION: u32 = 1;
+
+/// A gameplay input, as the player (or autopilot) gave it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Input {
+    Move { player: usize, direction: i32 }, // Keys, one frame's step
+    MoveTo { player: usize, x: i32 },       // Mouse
+    Steer { player: usize, x: i32 },        // Autopilot
+    AimAt { player: usize, x: i32, y: i32 },
+    Launch { player: usize },
+    Action { player: usize }, // Launch or fire a rocket
+}
+
+impl Input {
+    pub fn apply(self, game: &mut Game, play_sound: &mut dyn FnMut(SoundEffect)) {
+        match self {
+            Input::Move { player, direction } => game.move_paddle(player, direction),
+            Input::MoveTo { player, x } => game.move_paddle_to(player, x),
+            Input::Steer { player, x } => game.steer_paddle(player, x),
+            Input::AimAt { player, x, y } => game.aim_at(player, x, y),
+            Input::Launch { player } => game.launch_balls(player),
+            Input::Action { player } => game.press_action(player, play_sound),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct TimedInput {
+    pub frame: u64, // Play frame the input was given before
+    pub input: Input,
+}
+
+/// A recorded run: how it was started, then every input by play frame.
+/// Play frames only count while the game is running, so pauses do not matter.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,
+    pub level: usize,
+    #[serde(default)]
+    pub pack: String, // Campaign pack id, empty for the built-in campaign
+    #[serde(default)]
+    pub pattern: Option<String>, // Level file played instead of the campaign level
+    #[serde(default)]
+    pub modifiers: Vec<ModifierKind>,
+    pub auto_launch: bool,
+    pub heart_rescue: bool,
+    #[serde(default)]
+    pub portal: PortalConfig,
+    #[serde(default)]
+    pub frames: u64, // Length of the recording
+    #[serde(default)]
+    pub inputs: Vec<TimedInput>,
+}
+
+impl Replay {
+    /// Empty recording of a run about to start
+    pub fn new(seed: u64, level: usize, pattern: Option<String>, modifiers: Vec<ModifierKind>) -> Self {
+        Replay {
+            version: REPLAY_FORMAT_VERSION,
+            seed,
+            level,
+            pack: packs::current().id.clone(),
+            pattern,
+            modifiers,
+            auto_launch: true,
+            heart_rescue: false,
+            portal: PortalConfig::default(),
+            frames: 0,
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        let replay: Replay = serde_json::from_str(&content).map_err(|e| format!("{}: {}", path, e))?;
+        if replay.version > REPLAY_FORMAT_VERSION {
+            return Err(format!("{}: replay format v{} is newer than this game (v{})", path, replay.version, REPLAY_FORMAT_VERSION));
+        }
+        Ok(replay)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Select the pack, reseed and set up the game this run started from, ready to play
+    pub fn start_game(&self) -> Result<Game, String> {
+        let pack = packs::packs().packs.iter().position(|pack| pack.id == self.pack)
+            .ok_or_else(|| format!("Replay needs campaign pack '{}', which is not installed", self.pack))?;
+        packs::select(pack);
+        rng::reseed(self.seed);
+
+        let mut game = match self.pattern {
+            Some(ref path) => {
+                let pattern = PatternData::load_from_file(path).map_err(|e| format!("{}: {}", path, e))?;
+                Game::with_pattern(self.level, &pattern)
+            }
+            None => Game::new_level(self.level),
+        };
+        game.set_modifiers(&self.modifiers);
+        game.auto_launch = self.auto_launch;
+        game.heart_rescue = self.heart_rescue;
+        game.portal = self.portal.clone();
+        game.state = GameState::Playing;
+        Ok(game)
+    }
+}
+
+/// Records the inputs of a run as it is played, saved on exit
+pub struct Recorder {
+    pub path: String,
+    pub replay: Replay,
+}
+
+impl Recorder {
+    pub fn new(path: String, replay: Replay) -> Self {
+        Recorder { path, replay }
+    }
+
+    pub fn record(&mut self, input: Input) {
+        self.replay.inputs.push(TimedInput { frame: self.replay.frames, input });
+    }
+
+    /// One play frame went by
+    pub fn tick(&mut self) {
+        self.replay.frames += 1;
+    }
+
+    pub fn save(&self) {
+        match self.replay.save(&self.path) {
+            Ok(()) => println!("Replay saved to {} ({} frames)", self.path, self.replay.frames),
+            Err(e) => eprintln!("Failed to save replay {}: {}", self.path, e),
+        }
+    }
+}
+
+/// Apply an input from the player, recording it if a recording is running
+pub fn send(game: &mut Game, recorder: Option<&mut Recorder>, input: Input, play_sound: &mut dyn FnMut(SoundEffect)) {
+    if let Some(recorder) = recorder {
+        recorder.record(input);
+    }
+    input.apply(game, play_sound);
+}
+
+/// Feeds a recording back into a game, one play frame at a time
+pub struct Playback {
+    replay: Replay,
+    frame: u64,
+    next: usize, // Next input to apply
+}
+
+impl Playback {
+    pub fn new(replay: Replay) -> Self {
+        Playback { replay, frame: 0, next: 0 }
+    }
+
+    /// Apply this play frame's inputs; call once per frame the game runs
+    pub fn tick(&mut self, game: &mut Game, play_sound: &mut dyn FnMut(SoundEffect)) {
+        while let Some(timed) = self.replay.inputs.get(self.next) {
+            if timed.frame > self.frame {
+                break;
+            }
+            timed.input.apply(game, play_sound);
+            self.next += 1;
+        }
+        self.frame += 1;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.replay.frames
+    }
+}
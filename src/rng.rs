@@ -0,0 +1,48 @@
+use rand::rngs::StdRng;
+use rand::{Error, RngCore, SeedableRng};
+use std::cell::RefCell;
+
+// Gameplay randomness comes from one reseedable generator so a run can be
+// replayed exactly (agent training, balance tests). Seeded from entropy by default.
+thread_local! {
+    static GAME_RNG: RefCell<StdRng> = RefCell::new(StdRng::from_entropy());
+}
+
+/// Handle to the gameplay generator - use it like `rand::thread_rng()`
+pub struct GameRng;
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        GAME_RNG.with(|rng| rng.borrow_mut().next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        GAME_RNG.with(|rng| rng.borrow_mut().next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        GAME_RNG.with(|rng| rng.borrow_mut().fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        GAME_RNG.with(|rng| rng.borrow_mut().try_fill_bytes(dest))
+    }
+}
+
+pub fn game_rng() -> GameRng {
+    GameRng
+}
+
+/// Restart the gameplay generator from a fixed seed
+pub fn reseed(seed: u64) {
+    GAME_RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+/// Run `f` with `rng` standing in for the gameplay generator, so a side game
+/// (the title demo) leaves the player's random stream where it was
+pub fn with_rng<T>(rng: &mut StdRng, f: impl FnOnce() -> T) -> T {
+    GAME_RNG.with(|game| std::mem::swap(&mut *game.borrow_mut(), rng));
+    let result = f();
+    GAME_RNG.with(|game| std::mem::swap(&mut *game.borrow_mut(), rng));
+    result
+}
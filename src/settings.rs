@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
+use crate::modifiers::ModifierKind;
+use crate::ai::AiDifficulty;
+use crate::bonus_stage::PortalConfig;
 
 const SETTINGS_FILE: &str = "settings.json";
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Settings {
     pub window_width: u32,
     pub window_height: u32,
@@ -13,16 +16,42 @@ pub struct Settings {
     pub sfx_volume: i32,
     pub music_muted: bool,
     pub sfx_muted: bool,
-    pub gravity_mode: bool,
+    #[serde(default)]
+    pub modifiers: Vec<ModifierKind>,
+    #[serde(default)]
+    pub autopilot: Option<AiDifficulty>, // Computer drives Player 1's paddle (accessibility)
+    #[serde(default = "default_auto_launch")]
+    pub auto_launch: bool, // Resting balls launch by themselves; off leaves time to aim
+    #[serde(default)]
+    pub heart_rescue: bool, // The heart-stealing penguin can be knocked to win the life back
+    #[serde(default)]
+    pub portal: PortalConfig, // Portal threshold speed, rewards and bonus stage warp
+    #[serde(default)]
+    pub pack: String, // Campaign pack directory under packs/, empty for the built-in campaign
+    // Old single gravity toggle - read once and migrated into `modifiers`
+    #[serde(default, skip_serializing)]
+    gravity_mode: bool,
     #[serde(default = "default_vsync")]
     pub vsync: bool,
     #[serde(default = "default_resolution_width")]
     pub resolution_width: u32,
     #[serde(default = "default_resolution_height")]
     pub resolution_height: u32,
+    #[serde(skip)]
+    pub pinned: Pinned,
+}
+
+/// Saved values that command-line options replaced for this session
+#[derive(Debug, Clone, Default)]
+pub struct Pinned {
+    pub window_size: Option<(u32, u32)>,
+    pub fullscreen: Option<bool>,
+    pub muted: Option<(bool, bool)>, // Music, sound effects
+    pub modifiers: Option<Vec<ModifierKind>>,
 }
 
 fn default_vsync() -> bool { true }
+fn default_auto_launch() -> bool { true }
 fn default_resolution_width() -> u32 { 1280 }
 fn default_resolution_height() -> u32 { 720 }
 
@@ -36,10 +65,17 @@ impl Default for Settings {
             sfx_volume: 64,
             music_muted: false,
             sfx_muted: false,
+            modifiers: Vec::new(),
+            autopilot: None,
+            auto_launch: true,
+            heart_rescue: false,
+            portal: PortalConfig::default(),
+            pack: String::new(),
             gravity_mode: false,
             vsync: true,
             resolution_width: 1280,
             resolution_height: 720,
+            pinned: Pinned::default(),
         }
     }
 }
@@ -48,8 +84,11 @@ impl Settings {
     pub fn load() -> Self {
         if Path::new(SETTINGS_FILE).exists() {
             match fs::read_to_string(SETTINGS_FILE) {
-                Ok(content) => match serde_json::from_str(&content) {
-                    Ok(settings) => return settings,
+                Ok(content) => match serde_json::from_str::<Settings>(&content) {
+                    Ok(mut settings) => {
+                        settings.migrate();
+                        return settings;
+                    }
                     Err(e) => eprintln!("Failed to parse settings: {}", e),
                 },
                 Err(e) => eprintln!("Failed to read settings file: {}", e),
@@ -59,8 +98,33 @@ impl Settings {
         Self::default()
     }
 
+    /// Carry the old gravity toggle over to the modifier list
+    fn migrate(&mut self) {
+        if self.gravity_mode && !self.modifiers.contains(&ModifierKind::Gravity) {
+            self.modifiers.push(ModifierKind::Gravity);
+        }
+        self.gravity_mode = false;
+    }
+
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let json = serde_json::to_string_pretty(self)?;
+        // Session-only overrides go back to their saved values
+        let mut saved = self.clone();
+        if let Some((width, height)) = self.pinned.window_size {
+            saved.window_width = width;
+            saved.window_height = height;
+        }
+        if let Some(fullscreen) = self.pinned.fullscreen {
+            saved.fullscreen = fullscreen;
+        }
+        if let Some((music_muted, sfx_muted)) = self.pinned.muted {
+            saved.music_muted = music_muted;
+            saved.sfx_muted = sfx_muted;
+        }
+        if let Some(ref modifiers) = self.pinned.modifiers {
+            saved.modifiers = modifiers.clone();
+        }
+
+        let json = serde_json::to_string_pretty(&saved)?;
         fs::write(SETTINGS_FILE, json)?;
         Ok(())
     }
@@ -0,0 +1,135 @@
+/// What broke a block - used for the end-of-level breakdown
+#[derive(Clone, Copy, PartialEq)]
+pub enum DestroySource {
+    Ball,
+    Rocket,
+    Explosion, // Explosive block chain
+}
+
+/// Per-level statistics, collected while playing
+#[derive(Clone, Copy, Default)]
+pub struct LevelStats {
+    pub frames: u64, // Time spent playing this level (60 FPS)
+    pub blocks_by_ball: u32,
+    pub blocks_by_rocket: u32,
+    pub blocks_by_explosion: u32,
+    pub paddle_hits: u32,
+    pub bonuses_caught: u32,
+    pub bonuses_missed: u32,
+    pub lives_lost: u32,
+    pub hearts_rescued: u32, // Lost hearts knocked back from the penguin and caught
+    pub top_speed: f32, // px/s
+}
+
+impl LevelStats {
+    pub fn record_block(&mut self, source: DestroySource) {
+        match source {
+            DestroySource::Ball => self.blocks_by_ball += 1,
+            DestroySource::Rocket => self.blocks_by_rocket += 1,
+            DestroySource::Explosion => self.blocks_by_explosion += 1,
+        }
+    }
+
+    pub fn blocks_destroyed(&self) -> u32 {
+        self.blocks_by_ball + self.blocks_by_rocket + self.blocks_by_explosion
+    }
+
+    pub fn seconds(&self) -> u32 {
+        (self.frames / 60) as u32
+    }
+
+    /// Blocks broken by the ball per paddle hit, capped at 100%
+    pub fn accuracy_percent(&self) -> u32 {
+        if self.paddle_hits == 0 {
+            return if self.blocks_by_ball > 0 { 100 } else { 0 };
+        }
+        (self.blocks_by_ball * 100 / self.paddle_hits).min(100)
+    }
+}
+
+/// Bonus points for a finished level, plus the count-up animation state
+#[derive(Clone, Copy, Default)]
+pub struct LevelResults {
+    pub stats: LevelStats,
+    pub best_combo: u32,
+    pub time_bonus: u32,
+    pub no_death_bonus: u32,
+    pub accuracy_bonus: u32,
+    pub combo_bonus: u32,
+    pub reveal_timer: u32, // Frames since the results screen opened
+}
+
+/// Frames each bonus line takes to count up
+const COUNT_UP_FRAMES: u32 = 40;
+
+impl LevelResults {
+    /// Tally bonuses. `par_seconds` is the target clear time for the level.
+    pub fn new(stats: LevelStats, best_combo: u32, par_seconds: u32) -> Self {
+        LevelResults {
+            stats,
+            best_combo,
+            // 10 points for every second under par
+            time_bonus: par_seconds.saturating_sub(stats.seconds()) * 10,
+            no_death_bonus: if stats.lives_lost == 0 { 1000 } else { 0 },
+            accuracy_bonus: stats.accuracy_percent() * 5,
+            // 25 points per block in the best chain
+            combo_bonus: best_combo * 25,
+            reveal_timer: 0,
+        }
+    }
+
+    /// Apply the modifier score multiplier to every bonus
+    pub fn scale(&mut self, multiplier: f32) {
+        for bonus in [
+            &mut self.time_bonus,
+            &mut self.no_death_bonus,
+            &mut self.accuracy_bonus,
+            &mut self.combo_bonus,
+        ] {
+            *bonus = (*bonus as f32 * multiplier).round() as u32;
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.time_bonus + self.no_death_bonus + self.accuracy_bonus + self.combo_bonus
+    }
+
+    /// Bonus lines in display order
+    pub fn bonus_lines(&self) -> [(&'static str, u32); 4] {
+        [
+            ("Time bonus", self.time_bonus),
+            ("No-death bonus", self.no_death_bonus),
+            ("Accuracy bonus", self.accuracy_bonus),
+            ("Combo bonus", self.combo_bonus),
+        ]
+    }
+
+    pub fn update(&mut self) {
+        if !self.is_revealed() {
+            self.reveal_timer += 1;
+        }
+    }
+
+    /// All lines have finished counting up
+    pub fn is_revealed(&self) -> bool {
+        self.reveal_timer >= COUNT_UP_FRAMES * self.bonus_lines().len() as u32
+    }
+
+    /// Skip the animation
+    pub fn reveal_all(&mut self) {
+        self.reveal_timer = COUNT_UP_FRAMES * self.bonus_lines().len() as u32;
+    }
+
+    /// Value currently shown for a bonus line - lines count up one after another
+    pub fn displayed(&self, index: usize) -> u32 {
+        let value = self.bonus_lines()[index].1;
+        let start = index as u32 * COUNT_UP_FRAMES;
+        let elapsed = self.reveal_timer.saturating_sub(start).min(COUNT_UP_FRAMES);
+        (value as u64 * elapsed as u64 / COUNT_UP_FRAMES as u64) as u32
+    }
+
+    /// Sum of everything counted so far
+    pub fn displayed_total(&self) -> u32 {
+        (0..self.bonus_lines().len()).map(|i| self.displayed(i)).sum()
+    }
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use crate::modifiers::ModifierKind;
+
+const BEST_TIMES_FILE: &str = "best_times.json";
+
+/// Time attack runs through the first levels of the campaign
+pub const TIME_ATTACK_LEVELS: usize = 5;
+
+/// Losing the last ball costs 10 seconds instead of a life
+pub const LOST_BALL_PENALTY_FRAMES: u64 = 600;
+
+/// Which bests a run is compared with: the campaign pack and the modifier
+/// selection, as "pack+Modifier+Modifier" ("built-in" for the built-in campaign)
+pub fn run_key(pack: &str, modifiers: &[ModifierKind]) -> String {
+    let mut key = if pack.is_empty() { "built-in".to_string() } else { pack.to_string() };
+    for kind in ModifierKind::ALL.iter().filter(|kind| modifiers.contains(kind)) {
+        key.push_str(&format!("+{:?}", kind));
+    }
+    key
+}
+
+/// Personal bests of one kind of run, stored in frames (60 FPS)
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct BestTimes {
+    pub splits: Vec<u64>, // Best time for each level, by index
+    pub total: Option<u64>, // Best full run
+}
+
+impl BestTimes {
+    /// Bests of every kind of run, by run key
+    fn load_all() -> BTreeMap<String, BestTimes> {
+        if Path::new(BEST_TIMES_FILE).exists() {
+            match fs::read_to_string(BEST_TIMES_FILE) {
+                Ok(content) => match serde_json::from_str(&content) {
+                    Ok(all) => return all,
+                    // Older files held one set of bests, from the built-in campaign
+                    Err(e) => match serde_json::from_str::<BestTimes>(&content) {
+                        Ok(best) => return BTreeMap::from([(run_key("", &[]), best)]),
+                        Err(_) => eprintln!("Failed to parse best times: {}", e),
+                    },
+                },
+                Err(e) => eprintln!("Failed to read best times file: {}", e),
+            }
+        }
+
+        BTreeMap::new()
+    }
+
+    pub fn load(key: &str) -> Self {
+        Self::load_all().remove(key).unwrap_or_default()
+    }
+
+    /// Store these as the bests of one kind of run, keeping the others
+    pub fn save(self, key: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mut all = Self::load_all();
+        all.insert(key.to_string(), self);
+        let json = serde_json::to_string_pretty(&all)?;
+        fs::write(BEST_TIMES_FILE, json)?;
+        Ok(())
+    }
+
+    pub fn split(&self, index: usize) -> Option<u64> {
+        self.splits.get(index).copied()
+    }
+}
+
+/// Running state of a time attack run
+pub struct TimeAttack {
+    pub elapsed_frames: u64, // Whole run, including penalties
+    pub level_start_frames: u64, // elapsed_frames when the current level started
+    pub penalty_frames: u64, // Penalties collected so far
+    pub last_penalty_frame: Option<u64>, // For the HUD "+10s" flash
+    pub splits: Vec<u64>, // Finished level times
+    pub key: String, // Campaign pack and modifiers the bests are kept for
+    pub best: BestTimes, // Personal bests at the start of the run
+    pub new_best_total: bool,
+}
+
+impl TimeAttack {
+    pub fn new(pack: &str, modifiers: &[ModifierKind]) -> Self {
+        let key = run_key(pack, modifiers);
+        TimeAttack {
+            elapsed_frames: 0,
+            level_start_frames: 0,
+            penalty_frames: 0,
+            last_penalty_frame: None,
+            splits: Vec::new(),
+            best: BestTimes::load(&key),
+            key,
+            new_best_total: false,
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.elapsed_frames += 1;
+    }
+
+    pub fn add_penalty(&mut self) {
+        self.elapsed_frames += LOST_BALL_PENALTY_FRAMES;
+        self.penalty_frames += LOST_BALL_PENALTY_FRAMES;
+        self.last_penalty_frame = Some(self.elapsed_frames);
+    }
+
+    /// Penalty flash is shown for 1.5 seconds
+    pub fn penalty_flash(&self) -> bool {
+        self.last_penalty_frame
+            .is_some_and(|frame| self.elapsed_frames.saturating_sub(frame) < 90)
+    }
+
+    pub fn current_split(&self) -> u64 {
+        self.elapsed_frames - self.level_start_frames
+    }
+
+    /// Close the current level's split
+    pub fn finish_level(&mut self) {
+        self.splits.push(self.current_split());
+        self.level_start_frames = self.elapsed_frames;
+    }
+
+    /// Difference between the last split and the personal best, in frames
+    pub fn last_split_delta(&self) -> Option<i64> {
+        let index = self.splits.len().checked_sub(1)?;
+        let best = self.best.split(index)?;
+        Some(self.splits[index] as i64 - best as i64)
+    }
+
+    /// Merge this run into the personal bests and write them to disk
+    pub fn finish_run(&mut self) {
+        let mut updated = BestTimes::load(&self.key);
+
+        for (i, &split) in self.splits.iter().enumerate() {
+            if i >= updated.splits.len() {
+                updated.splits.push(split);
+            } else if split < updated.splits[i] {
+                updated.splits[i] = split;
+            }
+        }
+
+        if updated.total.is_none_or(|best| self.elapsed_frames < best) {
+            updated.total = Some(self.elapsed_frames);
+            self.new_best_total = true;
+        }
+
+        if let Err(e) = updated.save(&self.key) {
+            eprintln!("Failed to save best times: {}", e);
+        }
+    }
+}
+
+/// Format frames as m:ss.cc
+pub fn format_time(frames: u64) -> String {
+    let centis = frames * 100 / 60;
+    format!("{}:{:02}.{:02}", centis / 6000, (centis / 100) % 60, centis % 100)
+}
+
+/// Format a split delta as +s.cc / -s.cc
+pub fn format_delta(frames: i64) -> String {
+    let sign = if frames < 0 { '-' } else { '+' };
+    let centis = frames.unsigned_abs() * 100 / 60;
+    format!("{}{}.{:02}", sign, centis / 100, centis % 100)
+}
@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use crate::editor::PatternData;
+use crate::entities::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Severity {
+    Error,   // The level cannot be played (or finished)
+    Warning, // Plays, but probably not as the author meant
+}
+
+/// One finding about a level
+#[derive(Clone, Debug)]
+pub struct Issue {
+    pub severity: Severity,
+    pub position: Option<(usize, usize)>, // Grid row and column (0-based)
+    pub message: String,
+}
+
+impl Issue {
+    fn error(position: Option<(usize, usize)>, message: String) -> Self {
+        Issue { severity: Severity::Error, position, message }
+    }
+
+    fn warning(position: Option<(usize, usize)>, message: String) -> Self {
+        Issue { severity: Severity::Warning, position, message }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        match self.position {
+            // Shown 1-based, like a text editor
+            Some((row, col)) => write!(f, "{}: row {}, col {}: {}", severity, row + 1, col + 1, self.message),
+            None => write!(f, "{}: {}", severity, self.message),
+        }
+    }
+}
+
+pub fn has_errors(issues: &[Issue]) -> bool {
+    issues.iter().any(|issue| issue.severity == Severity::Error)
+}
+
+/// Playability checks on a loaded pattern: something to destroy, and every
+/// destroyable block reachable by the ball
+pub fn validate_pattern(pattern: &PatternData) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let layout = pattern.layout;
+
+    let destroyable = |row: usize, col: usize| {
+        matches!(pattern.grid[row][col], Some(cell) if cell.block_type != BlockType::Undestroyable)
+    };
+
+    if !(0..layout.rows).any(|row| (0..layout.cols).any(|col| destroyable(row, col))) {
+        issues.push(Issue::error(None, "no destroyable blocks, the level would be over at once".to_string()));
+        return issues;
+    }
+
+    for position in sealed_blocks(pattern) {
+        issues.push(Issue::error(Some(position), "block is sealed in by undestroyable blocks".to_string()));
+    }
+
+    issues
+}
+
+/// Destroyable blocks the ball can never get to (grid row and column)
+pub fn sealed_blocks(pattern: &PatternData) -> Vec<(usize, usize)> {
+    let layout = pattern.layout;
+
+    let destroyable = |row: usize, col: usize| {
+        matches!(pattern.grid[row][col], Some(cell) if cell.block_type != BlockType::Undestroyable)
+    };
+    let passable = |row: usize, col: usize| {
+        pattern.grid[row][col].is_none_or(|cell| cell.block_type != BlockType::Undestroyable)
+    };
+
+    // Flood fill from the open space around the grid. The ball comes up from
+    // below, so the bottom row is always open; the sides and the space above
+    // the grid only when the ball fits past the grid's sides. Destroyable blocks
+    // are passable once hit, and a reached explosive clears everything in its blast.
+    let mut reached = vec![vec![false; layout.cols]; layout.rows];
+    let mut queue = VecDeque::new();
+    let sides_open = layout.offset_x() >= BALL_SIZE;
+    for (row, cols) in reached.iter_mut().enumerate() {
+        for (col, cell_reached) in cols.iter_mut().enumerate() {
+            let on_edge = row == layout.rows - 1
+                || (sides_open && (row == 0 || col == 0 || col == layout.cols - 1));
+            if on_edge && passable(row, col) {
+                *cell_reached = true;
+                queue.push_back((row, col));
+            }
+        }
+    }
+
+    let center = |row: usize, col: usize| {
+        let (x, y) = layout.cell_position(row, col);
+        (x as f32 + layout.block_width as f32 / 2.0, y as f32 + layout.block_height as f32 / 2.0)
+    };
+
+    while let Some((row, col)) = queue.pop_front() {
+        if matches!(pattern.grid[row][col], Some(cell) if cell.block_type == BlockType::Explosive) {
+            let (ex, ey) = center(row, col);
+            for (r, cols) in reached.iter_mut().enumerate() {
+                for (c, cell_reached) in cols.iter_mut().enumerate() {
+                    let (x, y) = center(r, c);
+                    let (dx, dy) = (x - ex, y - ey);
+                    if !*cell_reached && dx * dx + dy * dy <= EXPLOSION_RADIUS * EXPLOSION_RADIUS {
+                        *cell_reached = true;
+                        queue.push_back((r, c));
+                    }
+                }
+            }
+        }
+
+        let neighbours = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+        for (r, c) in neighbours {
+            if r < layout.rows && c < layout.cols && !reached[r][c] && passable(r, c) {
+                reached[r][c] = true;
+                queue.push_back((r, c));
+            }
+        }
+    }
+
+    let mut sealed = Vec::new();
+    for (row, cols) in reached.iter().enumerate() {
+        for (col, &cell_reached) in cols.iter().enumerate() {
+            if destroyable(row, col) && !cell_reached {
+                sealed.push((row, col));
+            }
+        }
+    }
+    sealed
+}
+
+/// Source checks for the ASCII format, whose loader silently drops what it
+/// cannot use
+pub fn lint_ascii(content: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    let mut row = 0;
+    for line in content.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+
+        if row == BLOCK_ROWS {
+            issues.push(Issue::warning(Some((row, 0)), format!("only {} rows are used, the rest are ignored", BLOCK_ROWS)));
+        }
+        if row >= BLOCK_ROWS {
+            row += 1;
+            continue;
+        }
+
+        let line = line.trim_end();
+        for (col, ch) in line.chars().enumerate() {
+            if col >= BLOCK_COLS {
+                issues.push(Issue::warning(
+                    Some((row, col)),
+                    format!("row is {} cells long, only {} are used", line.chars().count(), BLOCK_COLS),
+                ));
+                break;
+            }
+            if !matches!(ch, '0'..='8' | 'I' | 'E' | 'U' | '*' | ' ') {
+                issues.push(Issue::warning(Some((row, col)), format!("unknown glyph '{}' is left empty", ch)));
+            }
+        }
+
+        row += 1;
+    }
+
+    issues
+}
+
+/// Everything known about a level file: source lint (ASCII) plus playability
+pub fn validate_file(path: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    if Path::new(path).extension().and_then(|s| s.to_str()) != Some("json") {
+        if let Ok(content) = fs::read_to_string(path) {
+            issues.extend(lint_ascii(&content));
+        }
+    }
+
+    match PatternData::load_from_file(path) {
+        Ok(pattern) => issues.extend(validate_pattern(&pattern)),
+        Err(e) => issues.push(Issue::error(None, format!("cannot load: {}", e))),
+    }
+
+    issues
+}
+
+/// `--validate <path>`: report on a level file, or every level file in a
+/// directory. Returns false if any has errors.
+pub fn run_cli(path: &str) -> bool {
+    let mut files = Vec::new();
+    if Path::new(path).is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                let file = entry.path();
+                if matches!(file.extension().and_then(|s| s.to_str()), Some("txt") | Some("json")) {
+                    files.push(file.to_string_lossy().to_string());
+                }
+            }
+        }
+        files.sort();
+    } else {
+        files.push(path.to_string());
+    }
+
+    let mut ok = true;
+    for file in &files {
+        let issues = validate_file(file);
+        if issues.is_empty() {
+            let blocks = PatternData::load_from_file(file).map_or(0, |pattern| pattern.block_count());
+            println!("{}: ok ({} blocks)", file, blocks);
+        }
+        for issue in &issues {
+            println!("{}: {}", file, issue);
+        }
+        ok &= !has_errors(&issues);
+    }
+    ok
+}